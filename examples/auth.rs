@@ -1,39 +1,18 @@
-use std::time::Duration;
-
-use roblox_api::{
-    api::{auth, auth::v1::LoginType, auth_token_service, auth_token_service::v1::LoginStatus},
-    client::Client,
-};
+use roblox_api::{api::auth_token_service::v1::QuickLogin, client::Client, login::LoginState};
 
 #[tokio::main]
 async fn main() {
     let mut client = Client::default();
-    client.ensure_token().await.unwrap();
-
-    let token = auth_token_service::v1::login_create(&mut client)
-        .await
-        .unwrap();
 
-    client.ensure_token().await.unwrap();
-    loop {
-        let status =
-            auth_token_service::v1::login_status(&mut client, &token.code, &token.private_key)
-                .await
-                .unwrap();
+    let mut quick_login = QuickLogin::start(&mut client).await.unwrap();
+    println!("enter {} on a logged in device", quick_login.code());
 
-        if status.status == LoginStatus::Validated {
-            break;
-        } else {
-            std::thread::sleep(Duration::from_secs(1));
-        }
+    while let Some(status) = quick_login.next_status(&mut client).await.unwrap() {
+        println!("{:?}", status.status);
     }
 
-    auth::v1::login(
-        &mut client,
-        &token.code,
-        &token.private_key,
-        LoginType::AuthToken,
-    )
-    .await
-    .unwrap();
+    let (_flow, state) = quick_login.login(&mut client).await.unwrap();
+    if let LoginState::LoggedIn { user_id, .. } = state {
+        println!("logged in as {user_id}");
+    }
 }
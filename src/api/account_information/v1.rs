@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, client::Client};
+use crate::{Error, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://accountinformation.roblox.com/v1";
 
@@ -14,17 +14,96 @@ pub struct RobloxBadge {
 }
 
 pub async fn roblox_badges(client: &mut Client, id: u64) -> Result<Vec<RobloxBadge>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}/roblox-badges"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<Vec<RobloxBadge>>(response)
+    ApiRequest::get(URL, format!("users/{id}/roblox-badges"))
+        .send(client)
+        .await?
+        .json::<Vec<RobloxBadge>>()
         .await
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Phone {
+    /// Such as `US`
+    pub country_code: Option<String>,
+    /// The calling code, such as `1`
+    pub prefix: Option<String>,
+    /// Masked, only the last digits are shown
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub is_verified: bool,
+    pub verification_code_length: Option<u8>,
+}
+
+pub async fn phone(client: &mut Client) -> Result<Phone, Error> {
+    ApiRequest::get(URL, "phone")
+        .send(client)
+        .await?
+        .json::<Phone>()
+        .await
+}
+
+/// A verification code is texted to the new number, which is then given to `verify_phone`
+pub async fn change_phone(
+    client: &mut Client,
+    country_code: &str,
+    prefix: &str,
+    phone: &str,
+    password: &str,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        country_code: &'a str,
+        prefix: &'a str,
+        phone: &'a str,
+        password: &'a str,
+    }
+
+    ApiRequest::post(URL, "phone")
+        .json(&Request {
+            country_code,
+            prefix,
+            phone,
+            password,
+        })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn verify_phone(client: &mut Client, code: &str) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        code: &'a str,
+    }
+
+    ApiRequest::post(URL, "phone/verify")
+        .json(&Request { code })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+/// Texts the verification code again
+pub async fn resend_phone_code(client: &mut Client) -> Result<(), Error> {
+    ApiRequest::post(URL, "phone/resend").send(client).await?;
+
+    Ok(())
+}
+
+pub async fn remove_phone(client: &mut Client, password: &str) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        password: &'a str,
+    }
+
+    ApiRequest::post(URL, "phone/delete")
+        .json(&Request { password })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
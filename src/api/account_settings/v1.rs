@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, client::Client, request::ApiRequest};
+
+pub const URL: &str = "https://accountsettings.roblox.com/v1";
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Email {
+    /// Masked, such as `r****@roblox.com`
+    #[serde(rename = "emailAddress")]
+    pub address: Option<String>,
+    pub verified: bool,
+}
+
+pub async fn email(client: &mut Client) -> Result<Email, Error> {
+    ApiRequest::get(URL, "email")
+        .send(client)
+        .await?
+        .json::<Email>()
+        .await
+}
+
+/// A verification email is sent to the new address
+pub async fn change_email(client: &mut Client, email: &str, password: &str) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        #[serde(rename = "emailAddress")]
+        email: &'a str,
+        password: &'a str,
+    }
+
+    ApiRequest::post(URL, "email")
+        .json(&Request { email, password })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+/// Sends the verification email again
+pub async fn send_verification_email(client: &mut Client) -> Result<(), Error> {
+    ApiRequest::post(URL, "email/verify").send(client).await?;
+
+    Ok(())
+}
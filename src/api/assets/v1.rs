@@ -1,14 +1,16 @@
 use std::path::Path;
 
-use reqwest::{
-    header::{self, HeaderValue},
-    multipart::Form,
-};
+use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
 
-use crate::{AssetTypeId, DateTime, Error, client::Client};
+use crate::{
+    AssetTypeId, DateTime, Error,
+    client::{AuthKind, Client},
+    request::{ApiRequest, MultipartField},
+};
 
 pub const URL: &str = "https://apis.roblox.com/assets/user-auth/v1";
+pub const CLOUD_URL: &str = "https://apis.roblox.com/assets/v1";
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -84,17 +86,21 @@ pub struct AssetUploadStatus {
     pub response: Option<AssetUploadResponse>,
 }
 
+// the same api is served under a different path for api keys and oauth tokens
+fn url(client: &Client) -> &'static str {
+    match client.auth_kind() {
+        AuthKind::Cookie => URL,
+        AuthKind::ApiKey | AuthKind::OAuth => CLOUD_URL,
+    }
+}
+
 pub async fn asset(client: &mut Client, id: u64) -> Result<AssetInfo, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<AssetInfo>(response).await
+    ApiRequest::get(url(client), format!("assets/{id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<AssetInfo>()
+        .await
 }
 
 // this api also takes in a patch request to update an exists asset "{URL}/assets/{id}"
@@ -106,9 +112,6 @@ pub async fn upload(
     asset_type: AssetTypeId,
     creation_context: CreationContext,
 ) -> Result<AssetUploadStatus, Error> {
-    let mut headers = client.requestor.default_headers.clone();
-    headers.insert(header::ACCEPT, HeaderValue::from_str("*/*").unwrap());
-
     #[derive(Clone, Debug, Deserialize, Serialize)]
     #[serde(rename_all = "camelCase")]
     struct Request<'a> {
@@ -127,40 +130,24 @@ pub async fn upload(
     })
     .unwrap();
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/assets"))
-        .headers(headers)
-        .multipart(
-            Form::new()
-                .text("request", request)
-                .file("fileContent", &path)
-                .await
-                .unwrap(),
-        )
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<AssetUploadStatus>(response)
+    ApiRequest::post(url(client), "assets")
+        .header(header::ACCEPT, HeaderValue::from_static("*/*"))
+        .multipart(vec![
+            MultipartField::text("request", request),
+            MultipartField::file("fileContent", path)?,
+        ])
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<AssetUploadStatus>()
         .await
 }
 
 pub async fn status(client: &mut Client, operation_id: &str) -> Result<AssetUploadStatus, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/operations/{operation_id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<AssetUploadStatus>(response)
+    ApiRequest::get(url(client), format!("operations/{operation_id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<AssetUploadStatus>()
         .await
 }
@@ -1,3 +1,5 @@
 pub mod v1;
+pub mod v2;
+pub mod v3;
 
 pub const URL: &str = "https://auth.roblox.com";
@@ -1,14 +1,13 @@
 use std::time::SystemTime;
 
-use base64::{Engine, prelude::BASE64_STANDARD};
-use p256::{
-    ecdsa::{Signature, SigningKey, signature::Signer},
-    elliptic_curve::rand_core::OsRng,
-};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 
-use crate::{DateTime, Error, api::hba_service, client::Client};
+use crate::{
+    ApiError, DateTime, Error,
+    api::{auth, hba_service},
+    client::{Client, Cookie},
+    request::ApiRequest,
+};
 
 pub const URL: &str = "https://auth.roblox.com/v1";
 
@@ -62,14 +61,25 @@ pub struct TwoStepVerificationInfo {
 #[serde(rename_all = "camelCase")]
 pub struct LoginResponse {
     pub user: User,
+    // only set if the account has two step verification enabled
     #[serde(rename = "twoStepVerificationData")]
-    pub two_step_verification_info: TwoStepVerificationInfo,
+    pub two_step_verification_info: Option<TwoStepVerificationInfo>,
     #[serde(rename = "identityVerificationLoginTicket")]
-    pub verification_ticket: String,
+    pub verification_ticket: Option<String>,
+    #[serde(default)]
     pub is_banned: bool,
+    #[serde(default)]
     pub should_update_email: bool,
-    pub recovery_email: String,
-    pub account_blob: String,
+    pub recovery_email: Option<String>,
+    pub account_blob: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PinStatus {
+    pub is_enabled: bool,
+    /// Seconds until the pin locks again, `None` while locked
+    pub unlocked_until: Option<f64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -84,6 +94,7 @@ struct AuthenticationIntent {
     nonce: String,
 }
 
+// binds the session to the client's hba key, which then has to sign its requests
 async fn authentication_intent(client: &mut Client) -> Result<AuthenticationIntent, Error> {
     let nonce = hba_service::v1::server_nonce(client).await?;
 
@@ -92,14 +103,9 @@ async fn authentication_intent(client: &mut Client) -> Result<AuthenticationInte
         .unwrap()
         .as_secs();
 
-    let key = SigningKey::random(&mut OsRng);
-    let public_key = BASE64_STANDARD.encode(key.verifying_key().to_sec1_bytes());
-
-    let binding = format!("{}:{}:{}", public_key, unix, nonce);
-    let hash = Sha256::digest(binding);
-
-    let signature: Signature = key.sign(&hash[..]);
-    let signature = String::from_utf8_lossy(&signature.to_bytes()).to_string();
+    let key = client.hba_key_or_generate();
+    let public_key = key.public_key();
+    let signature = key.sign(format!("{}:{}:{}", public_key, unix, nonce).as_bytes());
 
     Ok(AuthenticationIntent {
         public_key,
@@ -109,12 +115,12 @@ async fn authentication_intent(client: &mut Client) -> Result<AuthenticationInte
     })
 }
 
-pub async fn login(
+pub(crate) async fn login_request(
     client: &mut Client,
     login: &str,
     key: &str,
     login_type: LoginType,
-) -> Result<LoginResponse, Error> {
+) -> Result<ApiRequest, Error> {
     #[derive(Serialize)]
     struct Request<'a> {
         #[serde(rename = "ctype")]
@@ -129,22 +135,28 @@ pub async fn login(
     }
 
     let authentication_intent = authentication_intent(client).await?;
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/login"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&Request {
-            login_type,
-            login,
-            key,
-            authentication_intent,
-        })
-        .send()
-        .await;
 
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<LoginResponse>(response).await
+    Ok(ApiRequest::post(URL, "login").json(&Request {
+        login_type,
+        login,
+        key,
+        authentication_intent,
+    }))
+}
+
+/// For a login that handles challenges and two step verification, see `login::LoginFlow`
+pub async fn login(
+    client: &mut Client,
+    login: &str,
+    key: &str,
+    login_type: LoginType,
+) -> Result<LoginResponse, Error> {
+    login_request(client, login, key, login_type)
+        .await?
+        .send(client)
+        .await?
+        .json::<LoginResponse>()
+        .await
 }
 
 pub async fn recommended_usernames_from_display_name(
@@ -159,23 +171,65 @@ pub async fn recommended_usernames_from_display_name(
         birthday: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!(
-            "{URL}/validators/recommendedUsernameFromDisplayName"
-        ))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, "validators/recommendedUsernameFromDisplayName")
         .json(&Request {
             display_name,
             birthday: birthday.to_string().as_str(),
         })
-        .send()
-        .await;
+        .send(client)
+        .await?
+        .json::<RecommendedUsernamesFromDisplayName>()
+        .await
+}
+
+/// Logs the session out and clears the cookie from the client
+pub async fn logout(client: &mut Client) -> Result<(), Error> {
+    ApiRequest::post(URL, "logout").send(client).await?;
+
+    client.clear_cookie();
+    Ok(())
+}
 
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<RecommendedUsernamesFromDisplayName>(response)
+/// Logs out every session but this one, which gets a new cookie that the client switches to
+pub async fn logout_from_all_other_sessions(client: &mut Client) -> Result<Cookie, Error> {
+    let response = ApiRequest::post(URL, "logoutfromallsessionsandreauthenticate")
+        .send(client)
+        .await?;
+
+    let cookie = auth::v3::cookie_from_headers(response.headers())
+        .ok_or(Error::ApiError(ApiError::Unauthorized))?;
+
+    client.set_cookie(cookie.clone());
+    Ok(cookie)
+}
+
+pub async fn pin_status(client: &mut Client) -> Result<PinStatus, Error> {
+    ApiRequest::get(URL, "account/pin")
+        .send(client)
+        .await?
+        .json::<PinStatus>()
         .await
 }
+
+/// Fails with `ApiError::PinIsLocked` after too many wrong attempts
+pub async fn pin_unlock(client: &mut Client, pin: &str) -> Result<PinStatus, Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        pin: &'a str,
+    }
+
+    ApiRequest::post(URL, "account/pin/unlock")
+        .json(&Request { pin })
+        .send(client)
+        .await?
+        .json::<PinStatus>()
+        .await
+}
+
+pub async fn pin_lock(client: &mut Client) -> Result<(), Error> {
+    ApiRequest::post(URL, "account/pin/lock")
+        .send(client)
+        .await?;
+
+    Ok(())
+}
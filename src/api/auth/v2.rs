@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::{Error, client::Client, request::ApiRequest};
+
+pub const URL: &str = "https://auth.roblox.com/v2";
+
+/// Other sessions are logged out once the password changes
+pub async fn change_password(
+    client: &mut Client,
+    current_password: &str,
+    new_password: &str,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        current_password: &'a str,
+        new_password: &'a str,
+    }
+
+    ApiRequest::post(URL, "user/passwords/change")
+        .json(&Request {
+            current_password,
+            new_password,
+        })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use reqwest::header::{self, HeaderMap};
+use serde::Serialize;
+
+use crate::{
+    ApiError, Error,
+    client::{Client, Cookie},
+    request::ApiRequest,
+};
+
+pub const URL: &str = "https://auth.roblox.com/v3";
+
+pub(crate) fn cookie_from_headers(headers: &HeaderMap) -> Option<Cookie> {
+    headers
+        .get_all(header::SET_COOKIE)
+        .iter()
+        .filter_map(|x| x.to_str().ok())
+        .filter_map(|x| x.strip_prefix(".ROBLOSECURITY="))
+        .map(|x| x.split(';').next().unwrap_or_default())
+        .find(|x| !x.is_empty())
+        .map(Cookie::from)
+}
+
+/// Finishes logging in after two step verification, `challenge_id` is the ticket given by the login
+pub async fn two_step_verification_login(
+    client: &mut Client,
+    user_id: u64,
+    challenge_id: &str,
+    verification_token: &str,
+    remember_device: bool,
+) -> Result<Cookie, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        challenge_id: &'a str,
+        verification_token: &'a str,
+        remember_device: bool,
+    }
+
+    let response = ApiRequest::post(URL, format!("users/{user_id}/two-step-verification/login"))
+        .json(&Request {
+            challenge_id,
+            verification_token,
+            remember_device,
+        })
+        .send(client)
+        .await?;
+
+    cookie_from_headers(response.headers()).ok_or(Error::ApiError(ApiError::Unauthorized))
+}
@@ -1,7 +1,14 @@
-use reqwest::Method;
+use std::time::{Duration, SystemTime};
+
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, client::Client};
+use crate::{
+    ApiError, DateTime, Error,
+    client::Client,
+    login::{LoginFlow, LoginState},
+    options::{self, CancellationToken},
+    request::{ApiRequest, ResponseKind},
+};
 
 pub const URL: &str = "https://apis.roblox.com/auth-token-service/v1";
 
@@ -10,7 +17,13 @@ pub const URL: &str = "https://apis.roblox.com/auth-token-service/v1";
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub enum LoginStatus {
     Created,
+    /// The code was entered on a logged in device, but not confirmed yet
+    UserLinked,
     Validated,
+    Cancelled,
+    /// Anything added after these
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -40,15 +53,8 @@ pub struct InspectionInfo {
 }
 
 pub async fn login_create(client: &mut Client) -> Result<LoginToken, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::POST,
-            &format!("{URL}/login/create"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::post(URL, "login/create")
+        .send(client)
         .await?
         .json::<LoginToken>()
         .await
@@ -60,15 +66,9 @@ pub async fn login_cancel(client: &mut Client, code: &str) -> Result<(), Error>
         code: &'a str,
     }
 
-    client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/login/cancel"),
-            Some(&Request { code }),
-            None,
-            None,
-        )
+    ApiRequest::post(URL, "login/cancel")
+        .json(&Request { code })
+        .send(client)
         .await?;
 
     Ok(())
@@ -86,15 +86,9 @@ pub async fn login_status(
         key: &'a str,
     }
 
-    client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/login/status"),
-            Some(&Request { code, key }),
-            None,
-            None,
-        )
+    ApiRequest::post(URL, "login/status")
+        .json(&Request { code, key })
+        .send(client)
         .await?
         .json::<LoginTokenStatus>()
         .await
@@ -106,15 +100,9 @@ pub async fn inspect_code(client: &mut Client, code: &str) -> Result<InspectionI
         code: &'a str,
     }
 
-    client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/login/enterCode"),
-            Some(&Request { code }),
-            None,
-            None,
-        )
+    ApiRequest::post(URL, "login/enterCode")
+        .json(&Request { code })
+        .send(client)
         .await?
         .json::<InspectionInfo>()
         .await
@@ -126,31 +114,192 @@ pub async fn validate_code(client: &mut Client, code: &str) -> Result<(), Error>
         code: &'a str,
     }
 
-    client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/login/validateCode"),
-            Some(&Request { code }),
-            None,
-            None,
-        )
+    ApiRequest::post(URL, "login/validateCode")
+        .json(&Request { code })
+        .send(client)
         .await?;
 
     Ok(())
 }
 
 pub async fn qr_code_image(client: &mut Client, key: &str, code: &str) -> Result<Vec<u8>, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/login/qr-code-image"),
-            None,
-            Some(&[("key", key), ("code", code)]),
-            None,
-        )
+    ApiRequest::get(URL, "login/qr-code-image")
+        .queries(&[("key", key), ("code", code)])
+        .expect(ResponseKind::Bytes)
+        .send(client)
         .await?
         .bytes()
         .await
 }
+
+// a code that can't be told when it expires would be polled forever
+fn expiry(time: &str) -> Result<SystemTime, Error> {
+    let time = chrono::DateTime::parse_from_rfc3339(time).map_err(|_| Error::BadJson)?;
+    let millis = time
+        .timestamp_millis()
+        .try_into()
+        .map_err(|_| Error::BadJson)?;
+    Ok(SystemTime::UNIX_EPOCH + Duration::from_millis(millis))
+}
+
+/// Logs in by having the code entered, or the qr code scanned, on a device that's already logged in
+///
+/// ```no_run
+/// # async fn run() -> Result<(), roblox_api::Error> {
+/// use roblox_api::{api::auth_token_service::v1::QuickLogin, client::Client, login::LoginState};
+///
+/// let mut client = Client::default();
+/// let mut quick_login = QuickLogin::start(&mut client).await?;
+/// println!("enter {} on a logged in device", quick_login.code());
+///
+/// while let Some(status) = quick_login.next_status(&mut client).await? {
+///     println!("{:?}", status.status);
+/// }
+///
+/// let (_flow, state) = quick_login.login(&mut client).await?;
+/// if let LoginState::LoggedIn { user_id, .. } = state {
+///     println!("logged in as {user_id}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuickLogin {
+    token: LoginToken,
+    image: Vec<u8>,
+    status: LoginStatus,
+    expires_at: SystemTime,
+    interval: Duration,
+    cancellation: Option<CancellationToken>,
+}
+
+impl QuickLogin {
+    /// Creates the code and fetches its qr code
+    pub async fn start(client: &mut Client) -> Result<Self, Error> {
+        client.ensure_token().await?;
+
+        let token = login_create(client).await?;
+        let image = qr_code_image(client, &token.private_key, &token.code).await?;
+
+        Ok(Self {
+            expires_at: expiry(&token.expiration_time.to_string())?,
+            token,
+            image,
+            status: LoginStatus::Created,
+            interval: Duration::from_secs(3),
+            cancellation: None,
+        })
+    }
+
+    /// How often the status gets polled, 3 seconds by default
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Cancelling the token cancels the code and stops the waiting with `Error::Cancelled`
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn code(&self) -> &str {
+        &self.token.code
+    }
+
+    /// The qr code as a png
+    pub fn image(&self) -> &[u8] {
+        &self.image
+    }
+
+    pub fn token(&self) -> &LoginToken {
+        &self.token
+    }
+
+    pub fn status(&self) -> &LoginStatus {
+        &self.status
+    }
+
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at
+    }
+
+    /// Waits for the status to change, `None` once it's validated or cancelled,
+    /// if the code expires first this fails with `Error::Expired`
+    pub async fn next_status(
+        &mut self,
+        client: &mut Client,
+    ) -> Result<Option<LoginTokenStatus>, Error> {
+        if matches!(self.status, LoginStatus::Validated | LoginStatus::Cancelled) {
+            return Ok(None);
+        }
+
+        match self.wait_for_change(client).await {
+            Err(Error::Cancelled) => {
+                // the code shouldn't be usable once cancelled
+                let _ = login_cancel(client, &self.token.code).await;
+                Err(Error::Cancelled)
+            }
+            result => result.map(Some),
+        }
+    }
+
+    /// Waits until the code is validated, then logs in with it, the cookie is set on the client
+    /// once logged in, the flow is returned to get through two step verification if needed
+    pub async fn login(mut self, client: &mut Client) -> Result<(LoginFlow, LoginState), Error> {
+        while self.next_status(client).await?.is_some() {}
+
+        if self.status == LoginStatus::Cancelled {
+            return Err(Error::Cancelled);
+        }
+
+        let mut flow = LoginFlow::auth_token(&self.token.code, &self.token.private_key);
+        let state = flow.start(client).await?;
+
+        Ok((flow, state))
+    }
+
+    pub async fn cancel(self, client: &mut Client) -> Result<(), Error> {
+        login_cancel(client, &self.token.code).await
+    }
+
+    async fn wait_for_change(&mut self, client: &mut Client) -> Result<LoginTokenStatus, Error> {
+        loop {
+            if self.is_expired() {
+                return Err(Error::Expired);
+            }
+
+            let status = self.run(self.poll(client)).await?;
+            self.expires_at = expiry(&status.expiration_time)?;
+
+            if status.status != self.status {
+                self.status = status.status.clone();
+                return Ok(status);
+            }
+
+            self.run(async {
+                options::sleep(self.interval).await;
+                Ok(())
+            })
+            .await?;
+        }
+    }
+
+    async fn poll(&self, client: &mut Client) -> Result<LoginTokenStatus, Error> {
+        let result = login_status(client, &self.token.code, &self.token.private_key).await;
+
+        // the first request only gets us the csrf token
+        if let Err(Error::ApiError(ApiError::TokenValidation)) = result {
+            return login_status(client, &self.token.code, &self.token.private_key).await;
+        }
+
+        result
+    }
+
+    async fn run<T>(&self, future: impl Future<Output = Result<T, Error>>) -> Result<T, Error> {
+        match &self.cancellation {
+            Some(token) => token.run(future).await,
+            None => future.await,
+        }
+    }
+}
@@ -1,8 +1,7 @@
-use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString, FromRepr};
 
-use crate::{Error, Paging, client::Client};
+use crate::{Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://avatar.roblox.com/v1";
 
@@ -158,15 +157,8 @@ pub struct UniverseAvatarSettings {
 
 /// Returns details about a specified user's avatar
 pub async fn user_avatar(client: &mut Client, id: u64) -> Result<AvatarResponse, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/avatar"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/avatar"))
+        .send(client)
         .await?
         .json::<AvatarResponse>()
         .await
@@ -180,19 +172,14 @@ pub async fn user_currently_wearing(client: &mut Client, id: u64) -> Result<Vec<
         ids: Vec<u64>,
     }
 
-    Ok(client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/currently-wearing"),
-            None,
-            None,
-            None,
-        )
-        .await?
-        .json::<Response>()
-        .await?
-        .ids)
+    Ok(
+        ApiRequest::get(URL, format!("users/{id}/currently-wearing"))
+            .send(client)
+            .await?
+            .json::<Response>()
+            .await?
+            .ids,
+    )
 }
 
 /// Sets the avatar's current assets to the list - Flagged as obsolete, does not support layered clothing meta params.
@@ -215,15 +202,9 @@ pub async fn avatar_set_wearing_assets(
         pub success: bool,
     }
 
-    Ok(client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/avatar/set-wearing-assets"),
-            Some(&Request { asset_ids: &assets }),
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, "avatar/set-wearing-assets")
+        .json(&Request { asset_ids: &assets })
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -243,15 +224,9 @@ pub async fn avatar_set_type(client: &mut Client, kind: AvatarType) -> Result<bo
         success: bool,
     }
 
-    Ok(client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/avatar/set-player-avatar-type"),
-            Some(&Request { avatar_type: kind }),
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, "avatar/set-player-avatar-type")
+        .json(&Request { avatar_type: kind })
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -268,15 +243,9 @@ pub async fn avatar_set_body_colors(
         success: bool,
     }
 
-    Ok(client
-        .requestor
-        .request::<BodyColors>(
-            Method::POST,
-            &format!("{URL}/avatar/set-body-colors"),
-            Some(&colors),
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, "avatar/set-body-colors")
+        .json(&colors)
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -290,15 +259,9 @@ pub async fn avatar_set_scales(client: &mut Client, scales: AvatarScales) -> Res
         success: bool,
     }
 
-    Ok(client
-        .requestor
-        .request::<AvatarScales>(
-            Method::POST,
-            &format!("{URL}/avatar/set-scales"),
-            Some(&scales),
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, "avatar/set-scales")
+        .json(&scales)
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -320,19 +283,13 @@ pub async fn user_outfits(
         None => "".to_string(),
     };
 
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/outfits"),
-            None,
-            Some(&[
-                ("page", cursor),
-                ("itemsPerPage", &limit),
-                ("isEditable", &is_editable),
-            ]),
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/outfits"))
+        .queries(&[
+            ("page", cursor),
+            ("itemsPerPage", &limit),
+            ("isEditable", &is_editable),
+        ])
+        .send(client)
         .await?
         .json::<OutfitsResponse>()
         .await
@@ -340,15 +297,8 @@ pub async fn user_outfits(
 
 /// Gets details about the contents of an outfit.
 pub async fn outfit_details(client: &mut Client, id: u64) -> Result<OutfitDetails, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/outfits/{id}/details"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::get(URL, format!("outfits/{id}/details"))
+        .send(client)
         .await?
         .json::<OutfitDetails>()
         .await
@@ -361,15 +311,8 @@ pub async fn remove_outfit(client: &mut Client, id: u64) -> Result<bool, Error>
         success: bool,
     }
 
-    Ok(client
-        .requestor
-        .request::<()>(
-            Method::POST,
-            &format!("{URL}/outfits/{id}/delete"),
-            None,
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, format!("outfits/{id}/delete"))
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -383,15 +326,8 @@ pub async fn universe_avatar_settings(
     client: &mut Client,
     id: u64,
 ) -> Result<UniverseAvatarSettings, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/avatar"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/avatar"))
+        .send(client)
         .await?
         .json::<UniverseAvatarSettings>()
         .await
@@ -2,7 +2,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://badges.roblox.com/v1";
 
@@ -99,47 +99,26 @@ async fn badges_generic<Response: DeserializeOwned>(
     sort_by: Option<BadgeSortBy>,
     paging: Paging<'_>,
 ) -> Result<Response, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
     let sort_by = match sort_by {
         Some(sort_by) => sort_by.to_string(),
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/{path}/badges"))
-        .query(&[
-            ("sortBy", sort_by),
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Response>(response).await
+    ApiRequest::get(URL, format!("{path}/badges"))
+        .query("sortBy", sort_by)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await
 }
 
 pub async fn information(client: &mut Client, id: u64) -> Result<Badge, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/badges/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Badge>(response).await
+    ApiRequest::get(URL, format!("badges/{id}"))
+        .send(client)
+        .await?
+        .json::<Badge>()
+        .await
 }
 
 pub async fn universe_badges(
@@ -160,27 +139,17 @@ pub async fn user_badges(
 }
 
 pub async fn remove(client: &mut Client, id: u64, user_id: u64) -> Result<(), Error> {
-    let result = client
-        .requestor
-        .client
-        .delete(format!("{URL}/user/{user_id}/badges/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<()>(response).await
+    ApiRequest::delete(URL, format!("user/{user_id}/badges/{id}"))
+        .send(client)
+        .await?
+        .json::<()>()
+        .await
 }
 
 pub async fn authenticated_remove(client: &mut Client, id: u64) -> Result<(), Error> {
-    let result = client
-        .requestor
-        .client
-        .delete(format!("{URL}/user/badges/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<()>(response).await
+    ApiRequest::delete(URL, format!("user/badges/{id}"))
+        .send(client)
+        .await?
+        .json::<()>()
+        .await
 }
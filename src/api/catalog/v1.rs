@@ -0,0 +1,207 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    AssetTypeId, Error, Paging, api::economy::v2::CreatorType, client::Client, request::ApiRequest,
+};
+
+pub const URL: &str = "https://catalog.roblox.com/v1";
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ItemType {
+    #[default]
+    Asset,
+    Bundle,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogItem {
+    pub id: u64,
+    pub item_type: ItemType,
+    #[serde(
+        default,
+        deserialize_with = "asset_type_from_repr",
+        serialize_with = "asset_type_to_repr"
+    )]
+    pub asset_type: Option<AssetTypeId>,
+    pub bundle_type: Option<u8>,
+    pub name: String,
+    pub description: Option<String>,
+    pub product_id: Option<u64>,
+    pub collectible_item_id: Option<String>,
+    pub creator_name: String,
+    pub creator_type: CreatorType,
+    #[serde(rename = "creatorTargetId")]
+    pub creator_id: u64,
+    #[serde(rename = "creatorHasVerifiedBadge", default)]
+    pub is_creator_verified: bool,
+    /// `None` when it's off sale
+    pub price: Option<u64>,
+    pub lowest_price: Option<u64>,
+    pub lowest_resale_price: Option<u64>,
+    /// Such as `Off Sale` or `Free`
+    pub price_status: Option<String>,
+    #[serde(default)]
+    pub favorite_count: u64,
+    /// Such as `Limited` or `Collectible`
+    #[serde(default)]
+    pub item_restrictions: Vec<String>,
+    #[serde(default)]
+    pub is_off_sale: bool,
+    #[serde(default)]
+    pub has_resellers: bool,
+    pub total_quantity: Option<u64>,
+    pub units_available_for_consumption: Option<u64>,
+    pub quantity_limit_per_user: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleItem {
+    pub id: u64,
+    pub name: String,
+    /// Such as `Asset` or `UserOutfit`
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub owned: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleCreator {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: CreatorType,
+    #[serde(rename = "hasVerifiedBadge", default)]
+    pub is_verified: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleProduct {
+    pub id: u64,
+    #[serde(default)]
+    pub is_for_sale: bool,
+    #[serde(default)]
+    pub is_free: bool,
+    pub price_in_robux: Option<u64>,
+    pub no_price_text: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleDetails {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    /// Such as `BodyParts` or `AvatarAnimations`
+    pub bundle_type: String,
+    pub items: Vec<BundleItem>,
+    pub creator: BundleCreator,
+    pub product: Option<BundleProduct>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AssetBundles {
+    #[serde(rename = "data")]
+    pub bundles: Vec<BundleDetails>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+// the catalog gives the asset type as its number, types that aren't known yet are `None`
+fn asset_type_from_repr<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<AssetTypeId>, D::Error> {
+    Ok(Option::<u8>::deserialize(deserializer)?.and_then(AssetTypeId::from_repr))
+}
+
+fn asset_type_to_repr<S: Serializer>(
+    asset_type: &Option<AssetTypeId>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    asset_type.map(|x| x as u8).serialize(serializer)
+}
+
+/// Details of assets and bundles in one go, the order isn't kept
+pub async fn item_details(
+    client: &mut Client,
+    items: &[(ItemType, u64)],
+) -> Result<Vec<CatalogItem>, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Item {
+        item_type: ItemType,
+        id: u64,
+    }
+
+    #[derive(Serialize)]
+    struct Request {
+        items: Vec<Item>,
+    }
+
+    #[derive(Deserialize)]
+    struct Response {
+        data: Vec<CatalogItem>,
+    }
+
+    let items = items
+        .iter()
+        .map(|(item_type, id)| Item {
+            item_type: *item_type,
+            id: *id,
+        })
+        .collect();
+
+    Ok(ApiRequest::post(URL, "catalog/items/details")
+        .json(&Request { items })
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .data)
+}
+
+pub async fn bundle_details(client: &mut Client, id: u64) -> Result<BundleDetails, Error> {
+    ApiRequest::get(URL, format!("bundles/{id}/details"))
+        .send(client)
+        .await?
+        .json::<BundleDetails>()
+        .await
+}
+
+pub async fn bundles_details(
+    client: &mut Client,
+    ids: &[u64],
+) -> Result<Vec<BundleDetails>, Error> {
+    let ids = ids
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    ApiRequest::get(URL, "bundles/details")
+        .query("bundleIds", ids)
+        .send(client)
+        .await?
+        .json::<Vec<BundleDetails>>()
+        .await
+}
+
+/// The bundles that include the asset
+pub async fn asset_bundles(
+    client: &mut Client,
+    asset_id: u64,
+    paging: Paging<'_>,
+) -> Result<AssetBundles, Error> {
+    ApiRequest::get(URL, format!("assets/{asset_id}/bundles"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<AssetBundles>()
+        .await
+}
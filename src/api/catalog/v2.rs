@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+use crate::{
+    AssetTypeId, Error, Paging,
+    api::{catalog::v1::CatalogItem, economy::v2::CreatorType},
+    client::Client,
+    request::ApiRequest,
+};
+
+pub const URL: &str = "https://catalog.roblox.com/v2";
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum Category {
+    #[default]
+    All,
+    Featured,
+    Collectibles,
+    Clothing,
+    BodyParts,
+    Gear,
+    Accessories,
+    AvatarAnimations,
+    CommunityCreations,
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SortType {
+    #[default]
+    Relevance = 0,
+    MostFavorited,
+    Bestselling,
+    RecentlyCreated,
+    PriceLowToHigh,
+    PriceHighToLow,
+}
+
+/// Filters for `search`, anything unset isn't filtered on
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchFilter<'a> {
+    pub keyword: Option<&'a str>,
+    pub category: Option<Category>,
+    /// Such as `Hats`, `ClassicShirts` or `Bundles`
+    pub subcategory: Option<&'a str>,
+    pub creator_name: Option<&'a str>,
+    pub creator: Option<(CreatorType, u64)>,
+    pub min_price: Option<u64>,
+    pub max_price: Option<u64>,
+    pub sort: Option<SortType>,
+    pub include_not_for_sale: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SearchResults {
+    pub keyword: Option<String>,
+    #[serde(rename = "data")]
+    pub items: Vec<CatalogItem>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendedItem {
+    #[serde(rename = "assetId")]
+    pub id: u64,
+    pub name: String,
+    pub price: Option<u64>,
+    pub premium_price: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendedCreator {
+    #[serde(rename = "creatorId")]
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "creatorType")]
+    pub kind: CreatorType,
+    #[serde(rename = "hasVerifiedBadge", default)]
+    pub is_verified: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Recommendation {
+    pub item: RecommendedItem,
+    pub creator: RecommendedCreator,
+}
+
+/// The limit has to be one of 10, 28, 30, 50, 60, 100 or 120, the sort order isn't used
+pub async fn search(
+    client: &mut Client,
+    filter: &SearchFilter<'_>,
+    paging: Paging<'_>,
+) -> Result<SearchResults, Error> {
+    let mut request = ApiRequest::get(URL, "search/items/details")
+        .query("Limit", paging.limit.unwrap_or(30))
+        .query("IncludeNotForSale", filter.include_not_for_sale);
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("Cursor", cursor);
+    }
+
+    if let Some(keyword) = filter.keyword {
+        request = request.query("Keyword", keyword);
+    }
+
+    if let Some(creator_name) = filter.creator_name {
+        request = request.query("CreatorName", creator_name);
+    }
+
+    if let Some(category) = filter.category {
+        request = request.query("Category", category);
+    }
+
+    if let Some(subcategory) = filter.subcategory {
+        request = request.query("Subcategory", subcategory);
+    }
+
+    if let Some((kind, id)) = &filter.creator {
+        let kind = match kind {
+            CreatorType::User => "User",
+            CreatorType::Group => "Group",
+        };
+
+        request = request
+            .query("CreatorType", kind)
+            .query("CreatorTargetId", id);
+    }
+
+    if let Some(price) = filter.min_price {
+        request = request.query("MinPrice", price);
+    }
+
+    if let Some(price) = filter.max_price {
+        request = request.query("MaxPrice", price);
+    }
+
+    if let Some(sort) = filter.sort {
+        request = request.query("SortType", sort as u8);
+    }
+
+    request.send(client).await?.json::<SearchResults>().await
+}
+
+/// Items similar to the asset
+pub async fn recommendations(
+    client: &mut Client,
+    asset_id: u64,
+    asset_type_id: AssetTypeId,
+    count: u8,
+) -> Result<Vec<Recommendation>, Error> {
+    #[derive(Deserialize)]
+    struct Response {
+        data: Vec<Recommendation>,
+    }
+
+    Ok(ApiRequest::get(URL, "recommendations/assets")
+        .query("assetId", asset_id)
+        .query("assetTypeId", asset_type_id as u8)
+        .query("numItems", count)
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .data)
+}
@@ -2,17 +2,17 @@ use serde::Serialize;
 
 use crate::{
     Error,
-    challenge::{Challenge, ChallengeMetadataRequest},
+    challenge::{
+        CAPTCHA_CHALLENGE_TYPE, CHEF_CHALLENGE_TYPE, CaptchaChallenge,
+        CaptchaChallengeMetadataRequest, Challenge, ChallengeMetadataRequest, ChefChallenge,
+    },
     client::Client,
+    request::ApiRequest,
 };
 
 pub const URL: &str = "https://apis.roblox.com/challenge/v1";
 
-pub async fn continue_challenge(
-    client: &mut Client,
-    challenge: &Challenge,
-    verification_token: &str,
-) -> Result<(), Error> {
+fn continue_request_with(id: &str, kind: &str, metadata: &impl Serialize) -> ApiRequest {
     #[derive(Debug, Serialize)]
     struct Request<'a> {
         #[serde(rename = "challengeId")]
@@ -23,27 +23,79 @@ pub async fn continue_challenge(
         metadata: &'a str,
     }
 
-    let metadata_json = serde_json::to_string(&ChallengeMetadataRequest {
-        verification_token: verification_token.to_string(),
-        challenge_id: challenge.metadata.server_challenge_id.clone(),
-        action_type: challenge.metadata.action_type,
-        remember_device: challenge.metadata.remember_device,
+    ApiRequest::post(URL, "continue").json(&Request {
+        id,
+        kind,
+        metadata: &serde_json::to_string(metadata).unwrap(),
     })
-    .unwrap();
-
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/continue"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&Request {
-            id: &challenge.id,
-            kind: &challenge.kind.to_string(),
-            metadata: &metadata_json,
-        })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+}
+
+pub(crate) fn continue_request(challenge: &Challenge, verification_token: &str) -> ApiRequest {
+    continue_request_with(
+        &challenge.id,
+        &challenge.kind.to_string(),
+        &ChallengeMetadataRequest {
+            verification_token: verification_token.to_string(),
+            challenge_id: challenge.metadata.server_challenge_id.clone(),
+            action_type: challenge.metadata.action_type,
+            remember_device: challenge.metadata.remember_device,
+        },
+    )
+}
+
+pub async fn continue_challenge(
+    client: &mut Client,
+    challenge: &Challenge,
+    verification_token: &str,
+) -> Result<(), Error> {
+    continue_request(challenge, verification_token)
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+pub(crate) fn continue_captcha_request(
+    challenge: &CaptchaChallenge,
+    captcha_token: &str,
+) -> ApiRequest {
+    continue_request_with(
+        &challenge.id,
+        CAPTCHA_CHALLENGE_TYPE,
+        &CaptchaChallengeMetadataRequest {
+            unified_captcha_id: challenge.metadata.unified_captcha_id.clone(),
+            captcha_token: captcha_token.to_string(),
+            action_type: challenge.metadata.action_type,
+        },
+    )
+}
+
+pub(crate) fn continue_chef_request(
+    challenge: &ChefChallenge,
+    metadata: &serde_json::Value,
+) -> ApiRequest {
+    continue_request_with(&challenge.id, CHEF_CHALLENGE_TYPE, metadata)
+}
+
+/// Redeems a captcha token obtained externally for the challenge
+pub async fn continue_captcha_challenge(
+    client: &mut Client,
+    challenge: &CaptchaChallenge,
+    captcha_token: &str,
+) -> Result<(), Error> {
+    continue_captcha_request(challenge, captcha_token)
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Submits the result of running the challenge's scripts
+pub async fn continue_chef_challenge(
+    client: &mut Client,
+    challenge: &ChefChallenge,
+    metadata: &serde_json::Value,
+) -> Result<(), Error> {
+    continue_chef_request(challenge, metadata)
+        .send(client)
+        .await?;
     Ok(())
 }
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
+
+// Open Cloud, these need a client made with an api key or oauth token
+pub const URL: &str = "https://apis.roblox.com/cloud/v2";
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: String,
+    pub path: String,
+    pub name: String,
+    pub display_name: String,
+    pub about: Option<String>,
+    pub locale: Option<String>,
+    pub premium: Option<bool>,
+    #[serde(rename = "idVerified")]
+    pub is_id_verified: Option<bool>,
+    #[serde(rename = "createTime")]
+    pub created: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Group {
+    pub id: String,
+    pub path: String,
+    #[serde(rename = "displayName")]
+    pub name: String,
+    pub description: String,
+    // in the form of "users/{id}"
+    pub owner: Option<String>,
+    pub member_count: u64,
+    pub public_entry_allowed: bool,
+    #[serde(rename = "locked")]
+    pub is_locked: bool,
+    #[serde(rename = "verified")]
+    pub is_verified: bool,
+    #[serde(rename = "createTime")]
+    pub created: DateTime,
+    #[serde(rename = "updateTime")]
+    pub updated: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMembership {
+    pub path: String,
+    // in the form of "users/{id}"
+    pub user: String,
+    // in the form of "groups/{id}/roles/{id}"
+    pub role: String,
+    #[serde(rename = "createTime")]
+    pub created: DateTime,
+    #[serde(rename = "updateTime")]
+    pub updated: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberships {
+    #[serde(rename = "groupMemberships")]
+    pub memberships: Vec<GroupMembership>,
+    #[serde(rename = "nextPageToken")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Universe {
+    pub path: String,
+    #[serde(rename = "displayName")]
+    pub name: String,
+    pub description: String,
+    // only one of these is set, in the form of "users/{id}" or "groups/{id}"
+    pub user: Option<String>,
+    pub group: Option<String>,
+    pub visibility: String,
+    pub root_place: Option<String>,
+    pub voice_chat_enabled: Option<bool>,
+    pub private_server_price_robux: Option<u64>,
+    #[serde(rename = "createTime")]
+    pub created: DateTime,
+    #[serde(rename = "updateTime")]
+    pub updated: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Place {
+    pub path: String,
+    #[serde(rename = "displayName")]
+    pub name: String,
+    pub description: String,
+    pub server_size: Option<u16>,
+    #[serde(rename = "createTime")]
+    pub created: DateTime,
+    #[serde(rename = "updateTime")]
+    pub updated: DateTime,
+}
+
+pub async fn user(client: &mut Client, id: u64) -> Result<User, Error> {
+    ApiRequest::get(URL, format!("users/{id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<User>()
+        .await
+}
+
+pub async fn group(client: &mut Client, id: u64) -> Result<Group, Error> {
+    ApiRequest::get(URL, format!("groups/{id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<Group>()
+        .await
+}
+
+/// The sort order of `paging` is ignored, Open Cloud doesn't support it
+pub async fn group_memberships(
+    client: &mut Client,
+    id: u64,
+    paging: Paging<'_>,
+) -> Result<GroupMemberships, Error> {
+    let mut request = ApiRequest::get(URL, format!("groups/{id}/memberships"))
+        .query("maxPageSize", paging.limit.unwrap_or(10))
+        .open_cloud();
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("pageToken", cursor);
+    }
+
+    request.send(client).await?.json::<GroupMemberships>().await
+}
+
+pub async fn universe(client: &mut Client, id: u64) -> Result<Universe, Error> {
+    ApiRequest::get(URL, format!("universes/{id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<Universe>()
+        .await
+}
+
+pub async fn place(client: &mut Client, universe_id: u64, id: u64) -> Result<Place, Error> {
+    ApiRequest::get(URL, format!("universes/{universe_id}/places/{id}"))
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<Place>()
+        .await
+}
+
+/// Shuts down every running server of the universe, so they restart on the latest version
+pub async fn restart_servers(client: &mut Client, universe_id: u64) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::post(URL, format!("universes/{universe_id}:restartServers"))
+        .json(&Request {})
+        .open_cloud()
+        .send(client)
+        .await?;
+
+    Ok(())
+}
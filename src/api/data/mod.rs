@@ -1,6 +1,10 @@
 use reqwest::header::{self, HeaderValue};
 
-use crate::{AssetTypeId, Error, client::Client};
+use crate::{
+    AssetTypeId, Error,
+    client::Client,
+    request::{ApiRequest, ResponseKind},
+};
 
 pub const URL: &str = "https://data.roblox.com/data";
 
@@ -11,6 +15,7 @@ pub const URL: &str = "https://data.roblox.com/data";
 //
 /// `id` can be set to None, or Some(0) to upload a new asset, using an existing `id` will overwrite the old asset
 /// on success RETURNS the new asset id
+#[allow(clippy::too_many_arguments)]
 pub async fn upload(
     client: &mut Client,
     id: Option<u64>,
@@ -26,53 +31,42 @@ pub async fn upload(
     let id = id.unwrap_or(0);
     let genre_type_id = genre;
 
-    let mut url = format!("{URL}/upload.ashx?assetId={id}");
+    let mut request = ApiRequest::post(URL, "upload.ashx").query("assetId", id);
     if let AssetTypeId::Model = asset_type {
-        url.push_str("&type=Model");
+        request = request.query("type", "Model");
     } else if let AssetTypeId::Place = asset_type {
-        url.push_str("&type=Place");
+        request = request.query("type", "Place");
     } else {
-        let asset_type_id = asset_type as u8;
-        url.push_str(&format!("&assetTypeId={asset_type_id}"));
+        request = request.query("assetTypeId", asset_type as u8);
     }
 
     if let Some(group_id) = group_id {
-        url.push_str(&format!("&groupId={group_id}"));
+        request = request.query("groupId", group_id);
     }
 
-    let mut headers = client.requestor.default_headers.clone();
-    headers.insert(
-        header::ACCEPT,
-        HeaderValue::from_str("application/json").unwrap(),
-    );
+    let id: u64 = request
+        .query("name", name)
+        .query("description", description)
+        .query("genreTypeId", genre_type_id)
+        .query("isPublic", is_public)
+        .query("allowComments", allow_comments)
+        .header(header::ACCEPT, HeaderValue::from_static("application/json"))
+        .header(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/octect-stream"),
+        )
+        .header(
+            header::USER_AGENT,
+            HeaderValue::from_static("Roblox/WinInet"),
+        )
+        .raw(bytes.to_owned())
+        .expect(ResponseKind::Text)
+        .send(client)
+        .await?
+        .text()
+        .await?
+        .parse()
+        .unwrap();
 
-    headers.insert(
-        header::CONTENT_TYPE,
-        HeaderValue::from_str("application/octect-stream").unwrap(),
-    );
-
-    headers.insert(
-        header::USER_AGENT,
-        HeaderValue::from_str("Roblox/WinInet").unwrap(),
-    );
-
-    let result = client
-        .requestor
-        .client
-        .post(url)
-        .query(&[
-            ("name", name),
-            ("description", description),
-            ("genreTypeId", &genre_type_id.to_string()),
-            ("isPublic", &is_public.to_string()),
-            ("allowComments", &allow_comments.to_string()),
-        ])
-        .headers(headers)
-        .body(bytes.to_owned())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    let id: u64 = response.text().await.unwrap().parse().unwrap();
     Ok(id)
 }
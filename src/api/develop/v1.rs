@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://develop.roblox.com/v1";
 
@@ -79,24 +79,17 @@ pub async fn assets(client: &mut Client, ids: &[u64]) -> Result<Vec<Asset>, Erro
         .collect::<Vec<String>>()
         .join(",");
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets?assetIds={ids}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         assets: Vec<Asset>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "assets")
+        .query("assetIds", ids)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .assets)
 }
@@ -106,44 +99,18 @@ pub async fn published_asset_versions(
     id: u64,
     paging: Paging<'_>,
 ) -> Result<PublishedAssetVersions, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets/{id}/published-versions"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<PublishedAssetVersions>(response)
+    ApiRequest::get(URL, format!("assets/{id}/published-versions"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<PublishedAssetVersions>()
         .await
 }
 
 pub async fn revert_asset_version(client: &mut Client, id: u64, version: u64) -> Result<(), Error> {
-    let result = client
-        .requestor
-        .client
-        .post(format!(
-            "{URL}/assets/{id}/revert-version?assetVersionNumber={version}"
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+    ApiRequest::post(URL, format!("assets/{id}/revert-version"))
+        .query("assetVersionNumber", version)
+        .send(client)
+        .await?;
     Ok(())
 }
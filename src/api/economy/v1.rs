@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use strum_macros::Display;
 
-use crate::{Currency, Error, client::Client};
+use crate::{Currency, DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://economy.roblox.com/v1";
 
@@ -9,6 +10,92 @@ pub struct PurchaseResponse {
     pub purchased: bool,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Seller {
+    pub id: u64,
+    pub name: String,
+    /// Such as `User`
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(rename = "hasVerifiedBadge", default)]
+    pub is_verified: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reseller {
+    #[serde(rename = "userAssetId")]
+    pub instance_id: u64,
+    pub seller: Seller,
+    pub price: u64,
+    #[serde(rename = "serialNumber")]
+    pub serial: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Resellers {
+    #[serde(rename = "data")]
+    pub resellers: Vec<Reseller>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+/// One point of a resale time series, daily
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DataPoint {
+    pub value: u64,
+    pub date: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResaleData {
+    #[serde(rename = "assetStock")]
+    pub stock: Option<u64>,
+    pub sales: u64,
+    #[serde(rename = "numberRemaining")]
+    pub remaining: Option<u64>,
+    /// The RAP
+    pub recent_average_price: u64,
+    pub original_price: Option<u64>,
+    #[serde(rename = "priceDataPoints")]
+    pub prices: Vec<DataPoint>,
+    #[serde(rename = "volumeDataPoints")]
+    pub volumes: Vec<DataPoint>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum TimeFrame {
+    #[default]
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Robux the group made over a time frame, by where it came from
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RevenueSummary {
+    pub recurring_robux_stipend: i64,
+    pub item_sale_robux: i64,
+    pub purchased_robux: i64,
+    pub trade_system_robux: i64,
+    /// Sales that aren't available to the group yet
+    pub pending_robux: i64,
+    pub group_payout_robux: i64,
+    pub individual_to_group_robux: i64,
+    pub premium_payouts: i64,
+    pub group_premium_payouts: i64,
+    pub adjustment_robux: i64,
+    pub immersive_ad_payouts: i64,
+    pub subscription_payouts: i64,
+    pub subscription_clawbacks: i64,
+}
+
 /// This api seems to only give an Internal Server Error, try using `marketplace_sales::v1::purchase` instead
 pub async fn purchase(
     client: &mut Client,
@@ -27,95 +114,98 @@ pub async fn purchase(
         seller_user_id: Option<u64>,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/purchases/products/{product_id}"))
+    ApiRequest::post(URL, format!("purchases/products/{product_id}"))
         .json(&Request {
             currency: currency as u8,
             price,
             seller_user_id,
         })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<PurchaseResponse>(response)
+        .send(client)
+        .await?
+        .json::<PurchaseResponse>()
         .await
 }
 
 /// Returns how much `Currency::Robux` the authenticated user has
 pub async fn currency(client: &mut Client) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/user/currency"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         robux: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let response = client.requestor.parse_json::<Response>(response).await?;
+    let response = ApiRequest::get(URL, "user/currency")
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     Ok(response.robux)
 }
 
 /// Returns how much `Currency::Robux` the user has
 pub async fn currency_from_user_id(client: &mut Client, id: u64) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}/currency"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         robux: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("users/{id}/currency"))
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .robux)
 }
 
 /// Returns how much `Currency::Robux` the group has
 pub async fn currency_from_group_id(client: &mut Client, id: u64) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/currency"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         robux: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("groups/{id}/currency"))
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .robux)
 }
 
-// TODO:
-// assets/{id}/resellers
-// assets/{id}/resale-data
-// groups/{id}/revenue/summary/{date?}
-// groups/{id}/transactions - seems to be 404
+/// The cheapest resellers of a limited come first
+pub async fn resellers(
+    client: &mut Client,
+    asset_id: u64,
+    paging: Paging<'_>,
+) -> Result<Resellers, Error> {
+    ApiRequest::get(URL, format!("assets/{asset_id}/resellers"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Resellers>()
+        .await
+}
+
+/// The RAP and the price and volume history of a limited
+pub async fn resale_data(client: &mut Client, asset_id: u64) -> Result<ResaleData, Error> {
+    ApiRequest::get(URL, format!("assets/{asset_id}/resale-data"))
+        .send(client)
+        .await?
+        .json::<ResaleData>()
+        .await
+}
+
+pub async fn group_revenue_summary(
+    client: &mut Client,
+    group_id: u64,
+    time_frame: TimeFrame,
+) -> Result<RevenueSummary, Error> {
+    ApiRequest::get(
+        URL,
+        format!("groups/{group_id}/revenue/summary/{time_frame}"),
+    )
+    .send(client)
+    .await?
+    .json::<RevenueSummary>()
+    .await
+}
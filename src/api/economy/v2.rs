@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use strum_macros::Display;
 
-use crate::{DateTime, Error, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://economy.roblox.com/v2";
 
@@ -100,17 +101,107 @@ pub struct DetailsResponse {
 }
 
 pub async fn details(client: &mut Client, id: u64) -> Result<DetailsResponse, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets/{id}/details"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<DetailsResponse>(response)
+    ApiRequest::get(URL, format!("assets/{id}/details"))
+        .send(client)
+        .await?
+        .json::<DetailsResponse>()
+        .await
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum TransactionType {
+    #[default]
+    Sale,
+    Purchase,
+    AffiliateSale,
+    DevEx,
+    GroupPayout,
+    AdImpressionPayout,
+    CurrencyPurchase,
+    TradeRobux,
+    PremiumStipend,
+    EngagementPayout,
+    GroupEngagementPayout,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TransactionAgent {
+    pub id: u64,
+    /// Such as `User` or `Group`
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TransactionDetails {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    /// Such as `Asset` or `GamePass`
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TransactionCurrency {
+    pub amount: i64,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub id: u64,
+    pub id_hash: Option<String>,
+    pub transaction_type: Option<String>,
+    pub created: DateTime,
+    #[serde(default)]
+    pub is_pending: bool,
+    /// The other side of the transaction
+    pub agent: TransactionAgent,
+    pub details: Option<TransactionDetails>,
+    pub currency: TransactionCurrency,
+    pub purchase_token: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Transactions {
+    #[serde(rename = "data")]
+    pub transactions: Vec<Transaction>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+/// Only the authenticated user's transactions can be seen
+pub async fn user_transactions(
+    client: &mut Client,
+    user_id: u64,
+    transaction_type: TransactionType,
+    paging: Paging<'_>,
+) -> Result<Transactions, Error> {
+    ApiRequest::get(URL, format!("users/{user_id}/transactions"))
+        .query("transactionType", transaction_type)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Transactions>()
+        .await
+}
+
+pub async fn group_transactions(
+    client: &mut Client,
+    group_id: u64,
+    transaction_type: TransactionType,
+    paging: Paging<'_>,
+) -> Result<Transactions, Error> {
+    ApiRequest::get(URL, format!("groups/{group_id}/transactions"))
+        .query("transactionType", transaction_type)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Transactions>()
         .await
 }
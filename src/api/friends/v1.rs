@@ -1,7 +1,8 @@
-use reqwest::Method;
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://friends.roblox.com/v1";
 
@@ -124,15 +125,8 @@ async fn generic_count(client: &mut Client, path: &str) -> Result<u16, Error> {
         count: u16,
     }
 
-    Ok(client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/{path}/count"),
-            None,
-            None,
-            None,
-        )
+    Ok(ApiRequest::get(URL, format!("{path}/count"))
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -175,15 +169,9 @@ pub async fn following_status(
         statuses: Vec<FollowingStatus>,
     }
 
-    Ok(client
-        .requestor
-        .request::<Request>(
-            Method::POST,
-            &format!("{URL}/user/following-exists"),
-            Some(&Request { user_ids: ids }),
-            None,
-            None,
-        )
+    Ok(ApiRequest::post(URL, "user/following-exists")
+        .json(&Request { user_ids: ids })
+        .send(client)
         .await?
         .json::<Response>()
         .await?
@@ -197,45 +185,25 @@ pub async fn friend_requests(
     let limit = paging.limit.unwrap_or(18).to_string();
     let cursor = paging.cursor.unwrap_or("");
 
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/my/friends/requests"),
-            None,
-            Some(&[("cursor", cursor), ("limit", &limit)]),
-            None,
-        )
+    ApiRequest::get(URL, "my/friends/requests")
+        .queries(&[("cursor", cursor), ("limit", &limit)])
+        .send(client)
         .await?
         .json::<FriendRequests>()
         .await
 }
 
 pub async fn user_followers(client: &mut Client, id: u64) -> Result<Followers, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/followers"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/followers"))
+        .send(client)
         .await?
         .json::<Followers>()
         .await
 }
 
 pub async fn user_followings(client: &mut Client, id: u64) -> Result<Followers, Error> {
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/followings"),
-            None,
-            None,
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/followings"))
+        .send(client)
         .await?
         .json::<Followers>()
         .await
@@ -251,41 +219,30 @@ pub async fn user_friends_online(
         online: Vec<FriendOnlineStatus>,
     }
 
-    Ok(client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/friends/online"),
-            None,
-            None,
-            None,
-        )
+    Ok(ApiRequest::get(URL, format!("users/{id}/friends/online"))
+        .send(client)
         .await?
         .json::<Response>()
         .await?
         .online)
 }
 
+/// A page of the user's friends, pass `next_cursor` back in `paging` for the next one,
+/// the sort order of `paging` is ignored
 pub async fn user_friends_find(
     client: &mut Client,
     id: u64,
     paging: Paging<'_>,
 ) -> Result<FriendsFind, Error> {
-    let limit = paging.limit.unwrap_or(18).to_string();
-    let cursor = paging.cursor.unwrap_or("");
+    let mut request = ApiRequest::get(URL, format!("users/{id}/friends/find"))
+        .query("limit", paging.limit.unwrap_or(18))
+        .query("userSort", 1);
 
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/friends/find"),
-            None,
-            Some(&[("cursor", cursor), ("limit", &limit), ("userSort", "1")]),
-            None,
-        )
-        .await?
-        .json::<FriendsFind>()
-        .await
+    if let Some(cursor) = paging.cursor {
+        request = request.query("cursor", cursor);
+    }
+
+    request.send(client).await?.json::<FriendsFind>().await
 }
 
 pub async fn user_friends_search(
@@ -297,15 +254,9 @@ pub async fn user_friends_search(
     let limit = paging.limit.unwrap_or(36).to_string();
     let cursor = paging.cursor.unwrap_or("");
 
-    client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/friends/search"),
-            None,
-            Some(&[("cursor", &cursor), ("limit", &limit), ("query", query)]),
-            None,
-        )
+    ApiRequest::get(URL, format!("users/{id}/friends/search"))
+        .queries(&[("cursor", cursor), ("limit", &limit), ("query", query)])
+        .send(client)
         .await?
         .json::<FriendsFind>()
         .await
@@ -328,17 +279,106 @@ pub async fn user_friend_statuses(
         .collect::<Vec<String>>()
         .join(",");
 
-    Ok(client
-        .requestor
-        .request::<()>(
-            Method::GET,
-            &format!("{URL}/users/{id}/friends/statuses"),
-            None,
-            Some(&[("userIds", &ids)]),
-            None,
-        )
+    Ok(ApiRequest::get(URL, format!("users/{id}/friends/statuses"))
+        .query("userIds", &ids)
+        .send(client)
         .await?
         .json::<Response>()
         .await?
         .statuses)
 }
+
+/// Every friend of the user, goes through all the pages of `user_friends_find`,
+/// use that instead to stop early
+pub async fn user_friends_all(client: &mut Client, id: u64) -> Result<Vec<User>, Error> {
+    let mut users = Vec::new();
+    let mut cursors = HashSet::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let page =
+            user_friends_find(client, id, Paging::new(cursor.as_deref(), Some(50), None)).await?;
+
+        users.extend(page.users);
+        match page.next_cursor {
+            // a cursor that was already seen would go around forever
+            Some(next)
+                if !next.is_empty()
+                    && page.has_more != Some(false)
+                    && cursors.insert(next.clone()) =>
+            {
+                cursor = Some(next)
+            }
+            _ => return Ok(users),
+        }
+    }
+}
+
+pub async fn send_friend_request(
+    client: &mut Client,
+    id: u64,
+    source: FriendRequestSourceType,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request {
+        friendship_origin_source_type: FriendRequestSourceType,
+    }
+
+    ApiRequest::post(URL, format!("users/{id}/request-friendship"))
+        .json(&Request {
+            friendship_origin_source_type: source,
+        })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn accept_friend_request(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/accept-friend-request"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn decline_friend_request(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/decline-friend-request"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn decline_all_friend_requests(client: &mut Client) -> Result<(), Error> {
+    ApiRequest::post(URL, "user/friend-requests/decline-all")
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn unfriend(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/unfriend"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn follow(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/follow"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn unfollow(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/unfollow"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
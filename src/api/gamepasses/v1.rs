@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/game-passes/v1";
 
@@ -121,18 +121,10 @@ pub struct GamepassProductInformation {
 }
 
 pub async fn details(client: &mut Client, id: u64) -> Result<GamepassDetails, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/game-passes/{id}/details"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<GamepassDetails>(response)
+    ApiRequest::get(URL, format!("game-passes/{id}/details"))
+        .send(client)
+        .await?
+        .json::<GamepassDetails>()
         .await
 }
 
@@ -140,18 +132,10 @@ pub async fn product_information(
     client: &mut Client,
     id: u64,
 ) -> Result<GamepassProductInformation, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/game-passes/{id}/product-info"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<GamepassProductInformation>(response)
+    ApiRequest::get(URL, format!("game-passes/{id}/product-info"))
+        .send(client)
+        .await?
+        .json::<GamepassProductInformation>()
         .await
 }
 
@@ -167,25 +151,17 @@ pub async fn user_gamepasses(
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}/game-passes"))
-        .query(&[("count", limit), ("exclusiveStartId", cursor)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "gamePasses")]
         gamepasses: Vec<Gamepass>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("users/{id}/game-passes"))
+        .queries(&[("count", limit), ("exclusiveStartId", cursor)])
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .gamepasses)
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://games.roblox.com/v1";
 
@@ -205,19 +205,11 @@ pub async fn batch_place_details(
         .collect::<Vec<String>>()
         .join(",");
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/multiget-place-details"))
-        .query(&[("placeIds", ids)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<Vec<PlaceDetails>>(response)
+    ApiRequest::get(URL, "games/multiget-place-details")
+        .query("placeIds", ids)
+        .send(client)
+        .await?
+        .json::<Vec<PlaceDetails>>()
         .await
 }
 
@@ -229,31 +221,12 @@ pub async fn servers(
     exclude_full_games: bool,
     paging: Paging<'_>,
 ) -> Result<ServersResponse, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/{id}/servers/{server_kind}"))
-        .query(&[
-            ("excludeFullGames", exclude_full_games.to_string()),
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ServersResponse>(response)
+    ApiRequest::get(URL, format!("games/{id}/servers/{server_kind}"))
+        .query("excludeFullGames", exclude_full_games)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<ServersResponse>()
         .await
 }
 
@@ -263,69 +236,34 @@ pub async fn private_servers(
     exclude_friend_servers: bool,
     paging: Paging<'_>,
 ) -> Result<PrivateServersResponse, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/{id}/private-servers"))
-        .query(&[
-            ("excludeFriendServers", exclude_friend_servers.to_string()),
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<PrivateServersResponse>(response)
+    ApiRequest::get(URL, format!("games/{id}/private-servers"))
+        .query("excludeFriendServers", exclude_friend_servers)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<PrivateServersResponse>()
         .await
 }
 
 pub async fn private_server_info(client: &mut Client, id: u64) -> Result<PrivateServerInfo, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/vip-servers/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<PrivateServerInfo>(response)
+    ApiRequest::get(URL, format!("vip-servers/{id}"))
+        .send(client)
+        .await?
+        .json::<PrivateServerInfo>()
         .await
 }
 
 pub async fn universe_favorite_count(client: &mut Client, id: u64) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/{id}/favorites/count"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "favoritesCount")]
         favorites: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("games/{id}/favorites/count"))
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .favorites)
 }
@@ -337,25 +275,17 @@ pub async fn universe_votes(client: &mut Client, ids: &[u64]) -> Result<Vec<Univ
         .collect::<Vec<String>>()
         .join(",");
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/votes"))
-        .query(&[("universeIds", ids)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         votes: Vec<UniverseVotes>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "games/votes")
+        .query("universeIds", ids)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .votes)
 }
@@ -365,29 +295,10 @@ pub async fn universe_gamepasses(
     id: u64,
     paging: Paging<'_>,
 ) -> Result<UniverseGamepassesResponse, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/{id}/game-passes"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<UniverseGamepassesResponse>(response)
+    ApiRequest::get(URL, format!("games/{id}/game-passes"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<UniverseGamepassesResponse>()
         .await
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{AssetTypeId, DateTime, Error, Paging, client::Client};
+use crate::{AssetTypeId, DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://games.roblox.com/v2";
 
@@ -69,29 +69,13 @@ async fn games_generic(
     access_filter: u8,
     paging: Paging<'_>,
 ) -> Result<GamesResponse, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/{path}"))
-        .query(&[
-            ("accessFilter", access_filter.to_string()),
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<GamesResponse>(response).await
+    ApiRequest::get(URL, path)
+        .query("accessFilter", access_filter)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<GamesResponse>()
+        .await
 }
 
 pub async fn universe_media(
@@ -99,25 +83,17 @@ pub async fn universe_media(
     id: u64,
     all_experiences: bool,
 ) -> Result<Vec<UniverseMedia>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/games/{id}/media"))
-        .query(&[("fetchAllExperienceRelatedMedia", all_experiences)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         media: Vec<UniverseMedia>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("games/{id}/media"))
+        .query("fetchAllExperienceRelatedMedia", all_experiences)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .media)
 }
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use strum_macros::Display;
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, api::users, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://groups.roblox.com/v1";
 
@@ -225,19 +228,408 @@ pub struct GroupUsers {
     pub previous_cursor: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct JoinRequest {
+    pub requester: GroupUser,
+    pub created: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct JoinRequests {
+    #[serde(rename = "data")]
+    pub requests: Vec<JoinRequest>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupSearchResult {
+    pub id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    pub member_count: u64,
+    pub previous_name: Option<String>,
+    #[serde(rename = "publicEntryAllowed")]
+    pub is_public: bool,
+    pub created: DateTime,
+    pub updated: DateTime,
+    #[serde(rename = "hasVerifiedBadge")]
+    pub is_verified: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GroupSearch {
+    pub keyword: Option<String>,
+    #[serde(rename = "data")]
+    pub groups: Vec<GroupSearchResult>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupLookup {
+    pub id: u64,
+    pub name: String,
+    pub member_count: u64,
+    #[serde(rename = "hasVerifiedBadge")]
+    pub is_verified: bool,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum RelationshipType {
+    #[default]
+    Allies,
+    Enemies,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Relationships {
+    #[serde(rename = "totalGroupCount")]
+    pub total: u64,
+    #[serde(rename = "relatedGroups")]
+    pub groups: Vec<GroupInformation>,
+    /// The start index of the next page, `None` on the last page
+    pub next_row_index: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPolicy {
+    #[serde(rename = "groupId")]
+    pub id: u64,
+    pub can_view_group: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupsMetadata {
+    /// How many groups the authenticated user can be in
+    pub group_limit: u64,
+    pub current_group_count: u64,
+    pub group_status_max_length: u64,
+    pub group_post_max_length: u64,
+    #[serde(default)]
+    pub are_profile_groups_hidden: bool,
+    #[serde(default)]
+    pub show_previous_group_names: bool,
+}
+
+/// What was done, `Display` gives the name the audit log is filtered with
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum AuditActionType {
+    #[serde(rename = "Delete Post")]
+    DeletePost,
+    #[serde(rename = "Remove Member")]
+    RemoveMember,
+    #[serde(rename = "Accept Join Request")]
+    AcceptJoinRequest,
+    #[serde(rename = "Decline Join Request")]
+    DeclineJoinRequest,
+    #[serde(rename = "Post Status")]
+    PostStatus,
+    #[serde(rename = "Change Rank")]
+    ChangeRank,
+    #[serde(rename = "Buy Ad")]
+    BuyAd,
+    #[serde(rename = "Send Ally Request")]
+    SendAllyRequest,
+    #[serde(rename = "Create Enemy")]
+    CreateEnemy,
+    #[serde(rename = "Accept Ally Request")]
+    AcceptAllyRequest,
+    #[serde(rename = "Decline Ally Request")]
+    DeclineAllyRequest,
+    #[serde(rename = "Delete Ally")]
+    DeleteAlly,
+    #[serde(rename = "Delete Enemy")]
+    DeleteEnemy,
+    #[serde(rename = "Add Group Place")]
+    AddGroupPlace,
+    #[serde(rename = "Remove Group Place")]
+    RemoveGroupPlace,
+    #[serde(rename = "Create Items")]
+    CreateItems,
+    #[serde(rename = "Configure Items")]
+    ConfigureItems,
+    #[serde(rename = "Spend Group Funds")]
+    SpendGroupFunds,
+    #[serde(rename = "Change Owner")]
+    ChangeOwner,
+    Delete,
+    #[serde(rename = "Adjust Currency Amounts")]
+    AdjustCurrencyAmounts,
+    Abandon,
+    Claim,
+    Rename,
+    #[serde(rename = "Change Description")]
+    ChangeDescription,
+    #[serde(rename = "Create Group Asset")]
+    CreateGroupAsset,
+    #[serde(rename = "Update Group Asset")]
+    UpdateGroupAsset,
+    #[serde(rename = "Configure Group Asset")]
+    ConfigureGroupAsset,
+    #[serde(rename = "Revert Group Asset")]
+    RevertGroupAsset,
+    #[serde(rename = "Create Group Developer Product")]
+    CreateGroupDeveloperProduct,
+    #[serde(rename = "Configure Group Game")]
+    ConfigureGroupGame,
+    Lock,
+    Unlock,
+    #[serde(rename = "Create Game Pass")]
+    CreateGamePass,
+    #[serde(rename = "Create Badge")]
+    CreateBadge,
+    #[serde(rename = "Configure Badge")]
+    ConfigureBadge,
+    #[serde(rename = "Save Place")]
+    SavePlace,
+    #[serde(rename = "Publish Place")]
+    PublishPlace,
+    #[serde(rename = "Update Roleset Rank")]
+    UpdateRolesetRank,
+    #[serde(rename = "Update Roleset Data")]
+    UpdateRolesetData,
+    /// Anything added after these
+    #[serde(other)]
+    Unknown,
+}
+
+/// The description of the common actions, the rest are left as json
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditDescription {
+    ChangeRank {
+        target_id: u64,
+        target_name: String,
+        old_role_id: u64,
+        old_role_name: String,
+        new_role_id: u64,
+        new_role_name: String,
+    },
+    /// Removing a member, or accepting or declining their join request
+    Member {
+        target_id: u64,
+        target_name: String,
+    },
+    DeletePost {
+        target_id: u64,
+        target_name: String,
+        body: String,
+    },
+    PostStatus {
+        text: String,
+    },
+    SpendGroupFunds {
+        amount: u64,
+        item_description: String,
+    },
+    Rename {
+        new_name: String,
+    },
+    ChangeDescription {
+        new_description: String,
+    },
+    Other(serde_json::Value),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AuditActor {
+    pub user: GroupUser,
+    pub role: GroupRole,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    pub actor: AuditActor,
+    pub action_type: AuditActionType,
+    /// The raw description, see `AuditLogEntry::details` for it typed
+    pub description: serde_json::Value,
+    pub created: DateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AuditLog {
+    #[serde(rename = "data")]
+    pub entries: Vec<AuditLogEntry>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Payout {
+    pub user: GroupUser,
+    /// How much of the group's funds the user gets, in percent
+    pub percentage: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutRestriction {
+    pub can_use_recurring_payout: bool,
+    pub can_use_one_time_payout: bool,
+}
+
+impl AuditLogEntry {
+    pub fn details(&self) -> AuditDescription {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ChangeRank {
+            target_id: u64,
+            target_name: String,
+            old_role_set_id: u64,
+            old_role_set_name: String,
+            new_role_set_id: u64,
+            new_role_set_name: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Member {
+            target_id: u64,
+            target_name: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct DeletePost {
+            target_id: u64,
+            target_name: String,
+            post_desc: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct PostStatus {
+            text: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct SpendGroupFunds {
+            amount: u64,
+            item_description: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Rename {
+            new_name: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct ChangeDescription {
+            new_description: String,
+        }
+
+        fn parse<T: for<'de> Deserialize<'de>>(value: &serde_json::Value) -> Option<T> {
+            serde_json::from_value(value.clone()).ok()
+        }
+
+        let description = &self.description;
+        let details = match self.action_type {
+            AuditActionType::ChangeRank => {
+                parse::<ChangeRank>(description).map(|x| AuditDescription::ChangeRank {
+                    target_id: x.target_id,
+                    target_name: x.target_name,
+                    old_role_id: x.old_role_set_id,
+                    old_role_name: x.old_role_set_name,
+                    new_role_id: x.new_role_set_id,
+                    new_role_name: x.new_role_set_name,
+                })
+            }
+
+            AuditActionType::RemoveMember
+            | AuditActionType::AcceptJoinRequest
+            | AuditActionType::DeclineJoinRequest => {
+                parse::<Member>(description).map(|x| AuditDescription::Member {
+                    target_id: x.target_id,
+                    target_name: x.target_name,
+                })
+            }
+
+            AuditActionType::DeletePost => {
+                parse::<DeletePost>(description).map(|x| AuditDescription::DeletePost {
+                    target_id: x.target_id,
+                    target_name: x.target_name,
+                    body: x.post_desc,
+                })
+            }
+
+            AuditActionType::PostStatus => parse::<PostStatus>(description)
+                .map(|x| AuditDescription::PostStatus { text: x.text }),
+
+            AuditActionType::SpendGroupFunds => {
+                parse::<SpendGroupFunds>(description).map(|x| AuditDescription::SpendGroupFunds {
+                    amount: x.amount,
+                    item_description: x.item_description,
+                })
+            }
+
+            AuditActionType::Rename => {
+                parse::<Rename>(description).map(|x| AuditDescription::Rename {
+                    new_name: x.new_name,
+                })
+            }
+
+            AuditActionType::ChangeDescription => {
+                parse::<ChangeDescription>(description).map(|x| {
+                    AuditDescription::ChangeDescription {
+                        new_description: x.new_description,
+                    }
+                })
+            }
+
+            _ => None,
+        };
+
+        details.unwrap_or_else(|| AuditDescription::Other(description.clone()))
+    }
+}
+
+impl Permissions {
+    /// The flat `PascalCase` map the permissions are updated with
+    fn to_update(&self) -> serde_json::Map<String, serde_json::Value> {
+        let serde_json::Value::Object(groups) = serde_json::to_value(self).unwrap() else {
+            unreachable!();
+        };
+
+        groups
+            .into_values()
+            .filter_map(|x| match x {
+                serde_json::Value::Object(permissions) => Some(permissions),
+                _ => None,
+            })
+            .flatten()
+            .map(|(key, value)| {
+                let mut chars = key.chars();
+                let key = match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => key,
+                };
+
+                (key, value)
+            })
+            .collect()
+    }
+}
+
 pub async fn information(client: &mut Client, id: u64) -> Result<GroupInformation, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<GroupInformation>(response)
+    ApiRequest::get(URL, format!("groups/{id}"))
+        .send(client)
+        .await?
+        .json::<GroupInformation>()
         .await
 }
 
@@ -247,29 +639,16 @@ pub async fn membership(
     id: u64,
     notification_preferences: bool,
 ) -> Result<Membership, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/membership"))
-        .query(&[("includeNotificationPreferences", notification_preferences)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Membership>(response).await
+    ApiRequest::get(URL, format!("groups/{id}/membership"))
+        .query("includeNotificationPreferences", notification_preferences)
+        .send(client)
+        .await?
+        .json::<Membership>()
+        .await
 }
 
 /// Gets the Group's name change history
 pub async fn name_history(client: &mut Client, id: u64) -> Result<NameHistory, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/name-history"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct NameHistoryItem {
         name: String,
@@ -285,8 +664,11 @@ pub async fn name_history(client: &mut Client, id: u64) -> Result<NameHistory, E
         previous_cursor: Option<String>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let result = client.requestor.parse_json::<Response>(response).await?;
+    let result = ApiRequest::get(URL, format!("groups/{id}/name-history"))
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     let names: Vec<(String, DateTime)> = result
         .items
@@ -303,46 +685,30 @@ pub async fn name_history(client: &mut Client, id: u64) -> Result<NameHistory, E
 
 /// Gets groups that the authenticated user has requested to join
 pub async fn pending_join_requests(client: &mut Client) -> Result<Vec<GroupInformation>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/user/groups/pending"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         groups: Vec<GroupInformation>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "user/groups/pending")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .groups)
 }
 
 pub async fn roles(client: &mut Client, id: u64) -> Result<Vec<GroupRole>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/roles"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         roles: Vec<GroupRole>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, format!("groups/{id}/roles"))
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .roles)
 }
@@ -351,14 +717,6 @@ pub async fn user_roles(
     client: &mut Client,
     id: u64,
 ) -> Result<Vec<(GroupInformation, GroupRole)>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}/groups/roles"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct GroupAndRole {
         group: GroupInformation,
@@ -371,8 +729,11 @@ pub async fn user_roles(
         items: Vec<GroupAndRole>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let response = client.requestor.parse_json::<Response>(response).await?;
+    let response = ApiRequest::get(URL, format!("users/{id}/groups/roles"))
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     let mut roles = Vec::new();
     for item in &response.items {
@@ -388,66 +749,32 @@ pub async fn roleset_permissions(
     id: u64,
     roleset_id: u64,
 ) -> Result<RolePermissions, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/roles/{roleset_id}/permissions"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<RolePermissions>(response)
+    ApiRequest::get(URL, format!("groups/{id}/roles/{roleset_id}/permissions"))
+        .send(client)
+        .await?
+        .json::<RolePermissions>()
         .await
 }
 
 /// Gets all permissions for each role
 pub async fn role_permissions(client: &mut Client, id: u64) -> Result<Vec<RolePermissions>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/roles/permissions"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         items: Vec<RolePermissions>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
-        .await?
-        .items)
+    Ok(
+        ApiRequest::get(URL, format!("groups/{id}/roles/permissions"))
+            .send(client)
+            .await?
+            .json::<Response>()
+            .await?
+            .items,
+    )
 }
 
 pub async fn users(client: &mut Client, id: u64, paging: Paging<'_>) -> Result<GroupUsers, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/users"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct User {
         user: GroupUser,
@@ -464,8 +791,12 @@ pub async fn users(client: &mut Client, id: u64, paging: Paging<'_>) -> Result<G
         previous_cursor: Option<String>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let response = client.requestor.parse_json::<Response>(response).await?;
+    let response = ApiRequest::get(URL, format!("groups/{id}/users"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     let mut users = Vec::new();
     for user in response.users {
@@ -485,28 +816,12 @@ pub async fn wall_posts(
     id: u64,
     paging: Paging<'_>,
 ) -> Result<WallPosts, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/groups/{id}/wall/posts"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<WallPosts>(response).await
+    ApiRequest::get(URL, format!("groups/{id}/wall/posts"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<WallPosts>()
+        .await
 }
 
 pub async fn join(client: &mut Client, id: u64) -> Result<(), Error> {
@@ -517,19 +832,13 @@ pub async fn join(client: &mut Client, id: u64) -> Result<(), Error> {
         redemption_token: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/groups/{id}/users"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, format!("groups/{id}/users"))
         .json(&Request {
             session_id: "",
             redemption_token: "",
         })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
 
@@ -537,16 +846,10 @@ pub async fn remove_join_request(client: &mut Client, id: u64, user_id: u64) ->
     #[derive(Serialize)]
     struct Request {}
 
-    let result = client
-        .requestor
-        .client
-        .delete(format!("{URL}/groups/{id}/join-requests/users/{user_id}"))
+    ApiRequest::delete(URL, format!("groups/{id}/join-requests/users/{user_id}"))
         .json(&Request {})
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
 
@@ -554,15 +857,548 @@ pub async fn remove(client: &mut Client, id: u64, user_id: u64) -> Result<(), Er
     #[derive(Serialize)]
     struct Request {}
 
-    let result = client
-        .requestor
-        .client
-        .delete(format!("{URL}/groups/{id}/users/{user_id}"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::delete(URL, format!("groups/{id}/users/{user_id}"))
+        .json(&Request {})
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Changes the member's role, `role_id` is the id of a role from `roles`, not its rank
+pub async fn set_role(
+    client: &mut Client,
+    id: u64,
+    user_id: u64,
+    role_id: u64,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request {
+        role_id: u64,
+    }
+
+    ApiRequest::patch(URL, format!("groups/{id}/users/{user_id}"))
+        .json(&Request { role_id })
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Gets the users that requested to join the group
+pub async fn join_requests(
+    client: &mut Client,
+    id: u64,
+    paging: Paging<'_>,
+) -> Result<JoinRequests, Error> {
+    ApiRequest::get(URL, format!("groups/{id}/join-requests"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<JoinRequests>()
+        .await
+}
+
+#[derive(Serialize)]
+struct UserIdsRequest<'a> {
+    #[serde(rename = "UserIds")]
+    user_ids: &'a [u64],
+}
+
+pub async fn accept_join_requests(
+    client: &mut Client,
+    id: u64,
+    user_ids: &[u64],
+) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("groups/{id}/join-requests"))
+        .json(&UserIdsRequest { user_ids })
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+pub async fn decline_join_requests(
+    client: &mut Client,
+    id: u64,
+    user_ids: &[u64],
+) -> Result<(), Error> {
+    ApiRequest::delete(URL, format!("groups/{id}/join-requests"))
+        .json(&UserIdsRequest { user_ids })
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Posts the group shout, an empty message clears it
+pub async fn set_shout(client: &mut Client, id: u64, message: &str) -> Result<GroupShout, Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        message: &'a str,
+    }
+
+    ApiRequest::patch(URL, format!("groups/{id}/status"))
+        .json(&Request { message })
+        .send(client)
+        .await?
+        .json::<GroupShout>()
+        .await
+}
+
+pub async fn create_wall_post(client: &mut Client, id: u64, body: &str) -> Result<WallPost, Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        body: &'a str,
+    }
+
+    ApiRequest::post(URL, format!("groups/{id}/wall/posts"))
+        .json(&Request { body })
+        .send(client)
+        .await?
+        .json::<WallPost>()
+        .await
+}
+
+pub async fn delete_wall_post(client: &mut Client, id: u64, post_id: u64) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::delete(URL, format!("groups/{id}/wall/posts/{post_id}"))
+        .json(&Request {})
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Deletes every wall post made by the user
+pub async fn delete_user_wall_posts(
+    client: &mut Client,
+    id: u64,
+    user_id: u64,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::delete(URL, format!("groups/{id}/wall/users/{user_id}/posts"))
         .json(&Request {})
-        .send()
-        .await;
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Creating a role costs robux, `using_group_funds` pays for it from the group instead of the user
+pub async fn create_role(
+    client: &mut Client,
+    id: u64,
+    name: &str,
+    description: &str,
+    rank: u8,
+    using_group_funds: bool,
+) -> Result<GroupRole, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        name: &'a str,
+        description: &'a str,
+        rank: u8,
+        using_group_funds: bool,
+    }
+
+    ApiRequest::post(URL, format!("groups/{id}/rolesets/create"))
+        .json(&Request {
+            name,
+            description,
+            rank,
+            using_group_funds,
+        })
+        .send(client)
+        .await?
+        .json::<GroupRole>()
+        .await
+}
+
+pub async fn update_role(
+    client: &mut Client,
+    id: u64,
+    role_id: u64,
+    name: &str,
+    description: &str,
+    rank: u8,
+) -> Result<GroupRole, Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        name: &'a str,
+        description: &'a str,
+        rank: u8,
+    }
+
+    ApiRequest::patch(URL, format!("groups/{id}/rolesets/{role_id}"))
+        .json(&Request {
+            name,
+            description,
+            rank,
+        })
+        .send(client)
+        .await?
+        .json::<GroupRole>()
+        .await
+}
+
+/// The role can't have any members left
+pub async fn delete_role(client: &mut Client, id: u64, role_id: u64) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::delete(URL, format!("groups/{id}/rolesets/{role_id}"))
+        .json(&Request {})
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Replaces the role's permissions, such as with modified ones from `roleset_permissions`
+pub async fn update_role_permissions(
+    client: &mut Client,
+    id: u64,
+    role_id: u64,
+    permissions: &Permissions,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {
+        permissions: serde_json::Map<String, serde_json::Value>,
+    }
+
+    ApiRequest::patch(URL, format!("groups/{id}/roles/{role_id}/permissions"))
+        .json(&Request {
+            permissions: permissions.to_update(),
+        })
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Needs the view audit logs permission, `user_id` only shows what that user did
+pub async fn audit_log(
+    client: &mut Client,
+    id: u64,
+    action_type: Option<AuditActionType>,
+    user_id: Option<u64>,
+    paging: Paging<'_>,
+) -> Result<AuditLog, Error> {
+    let mut request = ApiRequest::get(URL, format!("groups/{id}/audit-log")).paging(paging, 10);
+
+    if let Some(action_type) = action_type {
+        request = request.query("actionType", action_type);
+    }
+
+    if let Some(user_id) = user_id {
+        request = request.query("userId", user_id);
+    }
+
+    request.send(client).await?.json::<AuditLog>().await
+}
+
+/// The recurring payouts, each user gets their percentage of the funds as they come in
+pub async fn payouts(client: &mut Client, id: u64) -> Result<Vec<Payout>, Error> {
+    #[derive(Deserialize)]
+    struct Response {
+        data: Vec<Payout>,
+    }
+
+    Ok(ApiRequest::get(URL, format!("groups/{id}/payouts"))
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .data)
+}
+
+pub async fn payout_restriction(client: &mut Client, id: u64) -> Result<PayoutRestriction, Error> {
+    ApiRequest::get(URL, format!("groups/{id}/payout-restriction"))
+        .send(client)
+        .await?
+        .json::<PayoutRestriction>()
+        .await
+}
+
+/// Whether the users can be paid out, keyed by user id, such as `Eligible` or `NotInGroup`
+pub async fn payout_eligibility(
+    client: &mut Client,
+    id: u64,
+    user_ids: &[u64],
+) -> Result<HashMap<String, String>, Error> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        users_group_payout_eligibility: HashMap<String, String>,
+    }
+
+    let user_ids = user_ids
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Ok(
+        ApiRequest::get(URL, format!("groups/{id}/payouts/eligibility"))
+            .query("userIds", user_ids)
+            .send(client)
+            .await?
+            .json::<Response>()
+            .await?
+            .users_group_payout_eligibility,
+    )
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PayoutRecipient {
+    recipient_id: u64,
+    recipient_type: &'static str,
+    amount: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct PayoutRequest {
+    payout_type: &'static str,
+    recipients: Vec<PayoutRecipient>,
+}
+
+fn payout_request(payout_type: &'static str, recipients: &[(u64, u64)]) -> PayoutRequest {
+    PayoutRequest {
+        payout_type,
+        recipients: recipients
+            .iter()
+            .map(|(user_id, amount)| PayoutRecipient {
+                recipient_id: *user_id,
+                recipient_type: "User",
+                amount: *amount,
+            })
+            .collect(),
+    }
+}
+
+/// Replaces the recurring payouts, `recipients` are `(user_id, percentage)`
+pub async fn set_recurring_payouts(
+    client: &mut Client,
+    id: u64,
+    recipients: &[(u64, u64)],
+) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("groups/{id}/payouts/recurring"))
+        .json(&payout_request("Percentage", recipients))
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// Pays robux out of the group funds, `recipients` are `(user_id, amount)`,
+/// usually challenged with two step verification, see `Client::set_challenge_solver`
+pub async fn one_time_payout(
+    client: &mut Client,
+    id: u64,
+    recipients: &[(u64, u64)],
+) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("groups/{id}/payouts"))
+        .json(&payout_request("FixedAmount", recipients))
+        .send(client)
+        .await?;
+    Ok(())
+}
+
+/// `exact_match_first` puts a group with exactly the keyword as its name first,
+/// the limit has to be one of 10, 25, 50 or 100
+pub async fn search(
+    client: &mut Client,
+    keyword: &str,
+    exact_match_first: bool,
+    paging: Paging<'_>,
+) -> Result<GroupSearch, Error> {
+    let mut request = ApiRequest::get(URL, "groups/search")
+        .query("keyword", keyword)
+        .query("prioritizeExactMatch", exact_match_first)
+        .query("limit", paging.limit.unwrap_or(10));
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("cursor", cursor);
+    }
+
+    request.send(client).await?.json::<GroupSearch>().await
+}
+
+/// Finds groups by their exact name
+pub async fn lookup(client: &mut Client, name: &str) -> Result<Vec<GroupLookup>, Error> {
+    #[derive(Deserialize)]
+    struct Response {
+        data: Vec<GroupLookup>,
+    }
+
+    Ok(ApiRequest::get(URL, "groups/search/lookup")
+        .query("groupName", name)
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .data)
+}
+
+/// Paged by row index, `start` is the `next_row_index` of the previous page
+pub async fn relationships(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    start: u64,
+    count: u16,
+) -> Result<Relationships, Error> {
+    ApiRequest::get(URL, format!("groups/{id}/relationships/{kind}"))
+        .query("StartRowIndex", start)
+        .query("MaxRows", count)
+        .send(client)
+        .await?
+        .json::<Relationships>()
+        .await
+}
+
+/// The relationship requests other groups sent to the group
+pub async fn relationship_requests(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    start: u64,
+    count: u16,
+) -> Result<Relationships, Error> {
+    ApiRequest::get(URL, format!("groups/{id}/relationships/{kind}/requests"))
+        .query("StartRowIndex", start)
+        .query("MaxRows", count)
+        .send(client)
+        .await?
+        .json::<Relationships>()
+        .await
+}
+
+/// Sends an ally request, or declares the group an enemy
+pub async fn request_relationship(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    related_id: u64,
+) -> Result<(), Error> {
+    ApiRequest::post(
+        URL,
+        format!("groups/{id}/relationships/{kind}/{related_id}"),
+    )
+    .send(client)
+    .await?;
+    Ok(())
+}
+
+pub async fn accept_relationship_request(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    related_id: u64,
+) -> Result<(), Error> {
+    ApiRequest::post(
+        URL,
+        format!("groups/{id}/relationships/{kind}/requests/{related_id}"),
+    )
+    .send(client)
+    .await?;
+    Ok(())
+}
 
-    client.requestor.validate_response(result).await?;
+pub async fn decline_relationship_request(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    related_id: u64,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::delete(
+        URL,
+        format!("groups/{id}/relationships/{kind}/requests/{related_id}"),
+    )
+    .json(&Request {})
+    .send(client)
+    .await?;
     Ok(())
 }
+
+pub async fn remove_relationship(
+    client: &mut Client,
+    id: u64,
+    kind: RelationshipType,
+    related_id: u64,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request {}
+
+    ApiRequest::delete(
+        URL,
+        format!("groups/{id}/relationships/{kind}/{related_id}"),
+    )
+    .json(&Request {})
+    .send(client)
+    .await?;
+    Ok(())
+}
+
+/// Whether the authenticated user is allowed to view the groups
+pub async fn policies(client: &mut Client, ids: &[u64]) -> Result<Vec<GroupPolicy>, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        group_ids: &'a [u64],
+    }
+
+    #[derive(Deserialize)]
+    struct Response {
+        groups: Vec<GroupPolicy>,
+    }
+
+    Ok(ApiRequest::post(URL, "groups/policies")
+        .json(&Request { group_ids: ids })
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .groups)
+}
+
+/// Limits of groups in the context of the authenticated user
+pub async fn metadata(client: &mut Client) -> Result<GroupsMetadata, Error> {
+    ApiRequest::get(URL, "groups/metadata")
+        .send(client)
+        .await?
+        .json::<GroupsMetadata>()
+        .await
+}
+
+/// The user's primary group and their role in it
+pub async fn primary_group(
+    client: &mut Client,
+    user_id: u64,
+) -> Result<Option<(GroupInformation, GroupRole)>, Error> {
+    #[derive(Deserialize)]
+    struct Response {
+        group: GroupInformation,
+        role: GroupRole,
+    }
+
+    Ok(
+        ApiRequest::get(URL, format!("users/{user_id}/groups/primary/role"))
+            .send(client)
+            .await?
+            .json::<Option<Response>>()
+            .await?
+            .map(|x| (x.group, x.role)),
+    )
+}
+
+/// Every group the authenticated user is in, with their role in each
+pub async fn authenticated_user_roles(
+    client: &mut Client,
+) -> Result<Vec<(GroupInformation, GroupRole)>, Error> {
+    let user = users::v1::authenticated_details(client).await?;
+    user_roles(client, user.id).await
+}
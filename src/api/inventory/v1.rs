@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{AssetTypeId, Error, Paging, client::Client};
+use crate::{AssetTypeId, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://inventory.roblox.com/v1";
 
@@ -64,26 +64,20 @@ pub struct UserOwnedCollectibles {
 }
 
 pub async fn can_view_inventory(client: &mut Client, user_id: u64) -> Result<bool, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{user_id}/can-view-inventory"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
     struct Response {
         can_view: bool,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
-        .await?
-        .can_view)
+    Ok(
+        ApiRequest::get(URL, format!("users/{user_id}/can-view-inventory"))
+            .send(client)
+            .await?
+            .json::<Response>()
+            .await?
+            .can_view,
+    )
 }
 
 pub async fn user_owns_assets(
@@ -100,19 +94,11 @@ pub async fn user_owns_assets(
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{user_id}/items/{item_type}/{id}"))
-        .query(&[("cursor", cursor)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<UserOwnsAssets>(response)
+    ApiRequest::get(URL, format!("users/{user_id}/items/{item_type}/{id}"))
+        .query("cursor", cursor)
+        .send(client)
+        .await?
+        .json::<UserOwnsAssets>()
         .await
 }
 
@@ -122,13 +108,6 @@ pub async fn user_owned_collectibles(
     asset_type_id: Option<AssetTypeId>,
     paging: Paging<'_>,
 ) -> Result<UserOwnedCollectibles, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
     let asset_type = match asset_type_id {
         Some(id) => {
             let id = id as u8;
@@ -137,23 +116,11 @@ pub async fn user_owned_collectibles(
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{user_id}/assets/collectibles"))
-        .query(&[
-            ("assetType", asset_type),
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<UserOwnedCollectibles>(response)
+    ApiRequest::get(URL, format!("users/{user_id}/assets/collectibles"))
+        .query("assetType", asset_type)
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<UserOwnedCollectibles>()
         .await
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{AssetTypeId, DateTime, Error, Paging, client::Client};
+use crate::{AssetTypeId, DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://inventory.roblox.com/v2";
 
@@ -79,28 +79,12 @@ pub async fn asset_owners(
     id: u64,
     paging: Paging<'_>,
 ) -> Result<AssetOwners, Error> {
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets/{id}/owners"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<AssetOwners>(response).await
+    ApiRequest::get(URL, format!("assets/{id}/owners"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<AssetOwners>()
+        .await
 }
 
 pub async fn user_owned_assets(
@@ -111,29 +95,10 @@ pub async fn user_owned_assets(
 ) -> Result<UserOwnedAssets, Error> {
     let asset_type_id = asset_type_id as u8;
 
-    let limit = paging.limit.unwrap_or(10).to_string();
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => cursor.to_string(),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{user_id}/inventory/{asset_type_id}"))
-        .query(&[
-            ("limit", limit),
-            ("sortOrder", sort_order),
-            ("cursor", cursor),
-        ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<UserOwnedAssets>(response)
+    ApiRequest::get(URL, format!("users/{user_id}/inventory/{asset_type_id}"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<UserOwnedAssets>()
         .await
 }
@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-use crate::{Currency, Error, client::Client};
+use crate::{Currency, Error, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/marketplace-sales/v1";
 
@@ -27,6 +26,8 @@ pub struct PurchaseResponse {
     pub error: Option<String>,
 }
 
+/// Set an idempotency key with `Client::with_options` and reuse it to safely retry a purchase,
+/// otherwise a random one is used and retrying can buy the item twice
 pub async fn purchase(
     client: &mut Client,
     asset_id: &str,
@@ -55,14 +56,9 @@ pub async fn purchase(
         seller_id: u64,
         #[serde(rename = "expectedSellerType")]
         seller_type: MarketEntityType,
-        idempotency_key: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/item/{asset_id}/purchase-item"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, format!("item/{asset_id}/purchase-item"))
         .json(&Request {
             asset_id,
             product_id,
@@ -72,14 +68,10 @@ pub async fn purchase(
             purchaser_type: purchaser.kind,
             seller_id: seller.id,
             seller_type: seller.kind,
-            idempotency_key: &Uuid::new_v4().to_string(),
         })
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<PurchaseResponse>(response)
+        .idempotency_key("idempotencyKey")
+        .send(client)
+        .await?
+        .json::<PurchaseResponse>()
         .await
 }
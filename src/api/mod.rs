@@ -1,11 +1,14 @@
 pub mod account_information;
+pub mod account_settings;
 pub mod asset_delivery;
 pub mod assets;
 pub mod auth;
 pub mod auth_token_service;
 pub mod avatar;
 pub mod badges;
+pub mod catalog;
 pub mod challenge;
+pub mod cloud;
 pub mod data;
 pub mod develop;
 pub mod economy;
@@ -17,12 +20,15 @@ pub mod hba_service;
 pub mod inventory;
 pub mod marketplace_sales;
 pub mod notifications;
+pub mod oauth;
 pub mod platform_chat;
 pub mod premium_features;
 pub mod presence;
 pub mod private_messages;
 pub mod thumbnails;
+pub mod token_metadata_service;
 pub mod toolbox_service;
+pub mod trades;
 pub mod two_step_verification;
 pub mod user_agreements;
 pub mod user_blocking;
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://notifications.roblox.com/v2";
 
@@ -159,18 +159,10 @@ pub struct Notification {
 }
 
 pub async fn unread_count(client: &mut Client) -> Result<NotificationUnreadCount, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/stream-notifications/unread-count"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<NotificationUnreadCount>(response)
+    ApiRequest::get(URL, "stream-notifications/unread-count")
+        .send(client)
+        .await?
+        .json::<NotificationUnreadCount>()
         .await
 }
 
@@ -181,66 +173,43 @@ pub async fn recent(client: &mut Client, paging: Paging<'_>) -> Result<Vec<Notif
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/stream-notifications/get-recent"))
-        .query(&[("maxRows", limit), ("startIndex", cursor)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<Vec<Notification>>(response)
+    ApiRequest::get(URL, "stream-notifications/get-recent")
+        .queries(&[("maxRows", limit), ("startIndex", cursor)])
+        .send(client)
+        .await?
+        .json::<Vec<Notification>>()
         .await
 }
 
 pub async fn clear_unread(client: &mut Client) -> Result<String, Error> {
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/stream-notifications/clear-unread"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
     struct Response {
         status_message: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "stream-notifications/clear-unread")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .status_message)
 }
 
 pub async fn dismiss(client: &mut Client, id: String) -> Result<String, Error> {
-    let result = client
-        .requestor
-        .client
-        .post(format!(
-            "{URL}/stream-notifications/clear-unread/action/{id}/SpecialItemIgnoreAction"
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
     struct Response {
         status_message: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
-        .await?
-        .status_message)
+    Ok(ApiRequest::post(
+        URL,
+        format!("stream-notifications/clear-unread/action/{id}/SpecialItemIgnoreAction"),
+    )
+    .send(client)
+    .await?
+    .json::<Response>()
+    .await?
+    .status_message)
 }
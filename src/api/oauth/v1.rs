@@ -0,0 +1,301 @@
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use p256::elliptic_curve::rand_core::{OsRng, RngCore};
+use reqwest::{
+    Url,
+    header::{self, HeaderValue},
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{ApiError, Error, client::Client, request::ApiRequest};
+
+pub const URL: &str = "https://apis.roblox.com/oauth/v1";
+
+/// Where each part of the flow lives, can be pointed at a local server for testing
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OAuthEndpoints {
+    pub authorize: String,
+    pub token: String,
+    pub revoke: String,
+    pub userinfo: String,
+    pub introspect: String,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    // public clients don't have one
+    pub client_secret: Option<String>,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub endpoints: OAuthEndpoints,
+}
+
+/// A PKCE code verifier and its `S256` challenge
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    // in seconds
+    pub expires_in: u64,
+    pub id_token: Option<String>,
+    pub scope: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct UserInfo {
+    // the user id
+    #[serde(rename = "sub")]
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(rename = "nickname")]
+    pub display_name: Option<String>,
+    #[serde(rename = "preferred_username")]
+    pub username: Option<String>,
+    #[serde(rename = "created_at")]
+    pub created: Option<u64>,
+    #[serde(rename = "profile")]
+    pub profile_url: Option<String>,
+    #[serde(rename = "picture")]
+    pub picture_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Introspection {
+    #[serde(rename = "active")]
+    pub is_active: bool,
+    pub client_id: Option<String>,
+    // the user id
+    #[serde(rename = "sub")]
+    pub user_id: Option<String>,
+    pub scope: Option<String>,
+    pub token_type: Option<String>,
+    // unix timestamps
+    #[serde(rename = "exp")]
+    pub expires: Option<u64>,
+    #[serde(rename = "iat")]
+    pub issued: Option<u64>,
+}
+
+// the secret and the tokens stay out of logs
+impl std::fmt::Debug for OAuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthConfig")
+            .field("client_id", &self.client_id)
+            .field(
+                "client_secret",
+                &self.client_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .field("redirect_uri", &self.redirect_uri)
+            .field("scopes", &self.scopes)
+            .field("endpoints", &self.endpoints)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for TokenResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenResponse")
+            .field("access_token", &"<redacted>")
+            .field("refresh_token", &"<redacted>")
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("id_token", &self.id_token.as_ref().map(|_| "<redacted>"))
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+impl Default for OAuthEndpoints {
+    fn default() -> Self {
+        Self::from_base(URL)
+    }
+}
+
+impl OAuthEndpoints {
+    /// Uses roblox's paths relative to `base`
+    pub fn from_base(base: &str) -> Self {
+        Self {
+            authorize: format!("{base}/authorize"),
+            token: format!("{base}/token"),
+            revoke: format!("{base}/token/revoke"),
+            userinfo: format!("{base}/userinfo"),
+            introspect: format!("{base}/token/introspect"),
+        }
+    }
+}
+
+impl OAuthConfig {
+    pub fn new(client_id: &str, client_secret: Option<&str>, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.map(|x| x.to_string()),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: vec!["openid".to_string()],
+            endpoints: OAuthEndpoints::default(),
+        }
+    }
+
+    pub fn scopes(mut self, scopes: &[&str]) -> Self {
+        self.scopes = scopes.iter().map(|x| x.to_string()).collect();
+        self
+    }
+
+    pub fn endpoints(mut self, endpoints: OAuthEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    fn credentials(&self) -> Vec<(&str, &str)> {
+        let mut pairs = vec![("client_id", self.client_id.as_str())];
+        if let Some(secret) = &self.client_secret {
+            pairs.push(("client_secret", secret.as_str()));
+        }
+
+        pairs
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pkce {
+    pub fn new() -> Self {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+
+        Self::from_verifier(&BASE64_URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn from_verifier(verifier: &str) -> Self {
+        Self {
+            verifier: verifier.to_string(),
+            challenge: BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(verifier)),
+        }
+    }
+}
+
+/// The url the user should be sent to, `state` is returned as is to the redirect uri
+pub fn authorization_url(config: &OAuthConfig, pkce: &Pkce, state: &str) -> String {
+    Url::parse_with_params(
+        &config.endpoints.authorize,
+        &[
+            ("client_id", config.client_id.as_str()),
+            ("redirect_uri", config.redirect_uri.as_str()),
+            ("scope", config.scopes.join(" ").as_str()),
+            ("response_type", "code"),
+            ("code_challenge", pkce.challenge.as_str()),
+            ("code_challenge_method", "S256"),
+            ("state", state),
+        ],
+    )
+    .unwrap()
+    .to_string()
+}
+
+pub async fn exchange_code(
+    client: &mut Client,
+    config: &OAuthConfig,
+    code: &str,
+    pkce: &Pkce,
+) -> Result<TokenResponse, Error> {
+    let mut form = config.credentials();
+    form.extend([
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("code_verifier", pkce.verifier.as_str()),
+        ("redirect_uri", config.redirect_uri.as_str()),
+    ]);
+
+    ApiRequest::post(&config.endpoints.token, "")
+        .form(&form)
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<TokenResponse>()
+        .await
+}
+
+pub(crate) fn refresh_request(config: &OAuthConfig, refresh_token: &str) -> ApiRequest {
+    let mut form = config.credentials();
+    form.extend([
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+    ]);
+
+    ApiRequest::post(&config.endpoints.token, "")
+        .form(&form)
+        .open_cloud()
+}
+
+/// The refresh token is rotated, the old one stops working
+pub async fn refresh(
+    client: &mut Client,
+    config: &OAuthConfig,
+    refresh_token: &str,
+) -> Result<TokenResponse, Error> {
+    refresh_request(config, refresh_token)
+        .send(client)
+        .await?
+        .json::<TokenResponse>()
+        .await
+}
+
+/// Revoking a refresh token also revokes the access tokens made from it
+pub async fn revoke(client: &mut Client, config: &OAuthConfig, token: &str) -> Result<(), Error> {
+    let mut form = config.credentials();
+    form.push(("token", token));
+
+    ApiRequest::post(&config.endpoints.revoke, "")
+        .form(&form)
+        .open_cloud()
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn introspect(
+    client: &mut Client,
+    config: &OAuthConfig,
+    token: &str,
+) -> Result<Introspection, Error> {
+    let mut form = config.credentials();
+    form.push(("token", token));
+
+    ApiRequest::post(&config.endpoints.introspect, "")
+        .form(&form)
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<Introspection>()
+        .await
+}
+
+pub async fn userinfo(
+    client: &mut Client,
+    config: &OAuthConfig,
+    access_token: &str,
+) -> Result<UserInfo, Error> {
+    // a token that can't be put in a header can't be a valid one either
+    let authorization = HeaderValue::from_str(&format!("Bearer {access_token}"))
+        .map_err(|_| Error::ApiError(ApiError::Unauthorized))?;
+
+    ApiRequest::get(&config.endpoints.userinfo, "")
+        .header(header::AUTHORIZATION, authorization)
+        .open_cloud()
+        .send(client)
+        .await?
+        .json::<UserInfo>()
+        .await
+}
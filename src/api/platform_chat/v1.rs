@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/platform-chat-api/v1";
 
@@ -135,18 +135,10 @@ pub struct ConversationCreateRequest {
 }
 
 pub async fn conversation_metadata(client: &mut Client) -> Result<ConversationMetadata, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/get-conversation-metadata"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ConversationMetadata>(response)
+    ApiRequest::get(URL, "get-conversation-metadata")
+        .send(client)
+        .await?
+        .json::<ConversationMetadata>()
         .await
 }
 
@@ -160,15 +152,6 @@ pub async fn conversations_participant_metadata(
         ids: &'a [&'a str],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/get-conversations-participants-metadata"))
-        .json(&Request { ids })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct ParticipantPending {
         is_pending: bool,
@@ -185,8 +168,12 @@ pub async fn conversations_participant_metadata(
         metadata: HashMap<String, ParticipantsMetadata>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let response = client.requestor.parse_json::<Response>(response).await?;
+    let response = ApiRequest::post(URL, "get-conversations-participants-metadata")
+        .json(&Request { ids })
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     let mut metadata = Vec::new();
     for (k, v) in &response.metadata {
@@ -216,22 +203,17 @@ pub async fn conversations(client: &mut Client, ids: &[&str]) -> Result<Conversa
         include_participants: bool,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/get-conversations"))
+    ApiRequest::post(URL, "get-conversations")
         .json(&Request {
             ids,
             include_messages: true,
             include_user_data: true,
             include_participants: true,
         })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Conversations>(response).await
+        .send(client)
+        .await?
+        .json::<Conversations>()
+        .await
 }
 
 pub async fn user_conversations(
@@ -244,40 +226,27 @@ pub async fn user_conversations(
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/get-user-conversations"))
-        .query(&[
+    ApiRequest::get(URL, "get-user-conversations")
+        .queries(&[
             ("cursor", cursor),
             ("include_user_data", true.to_string()),
             ("pageSize", limit),
         ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Conversations>(response).await
+        .send(client)
+        .await?
+        .json::<Conversations>()
+        .await
 }
 
 pub async fn conversation_messages(
     client: &mut Client,
     id: &str,
 ) -> Result<ConversationMessages, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/get-conversation-messages"))
-        .query(&[("conversation_id", id)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ConversationMessages>(response)
+    ApiRequest::get(URL, "get-conversation-messages")
+        .query("conversation_id", id)
+        .send(client)
+        .await?
+        .json::<ConversationMessages>()
         .await
 }
 
@@ -304,19 +273,11 @@ pub async fn send_messages_in_conversation(
         .map(|x| MessageToPost { content: x })
         .collect::<Vec<_>>();
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/send-messages"))
+    ApiRequest::post(URL, "send-messages")
         .json(&Request { id, messages })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ConversationMessages>(response)
+        .send(client)
+        .await?
+        .json::<ConversationMessages>()
         .await
 }
 
@@ -330,24 +291,16 @@ pub async fn update_typing_status_in_conversation(
         id: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/update-typing-status"))
-        .json(&Request { id })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         status: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "update-typing-status")
+        .json(&Request { id })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .status)
 }
@@ -365,24 +318,16 @@ pub async fn add_users_to_conversation(
         users: &'a [u64],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/add-users"))
-        .json(&Request { id, users })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         status: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "add-users")
+        .json(&Request { id, users })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .status)
 }
@@ -400,24 +345,16 @@ pub async fn remove_users_from_conversation(
         users: &'a [u64],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/remove-users"))
-        .json(&Request { id, users })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         status: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "remove-users")
+        .json(&Request { id, users })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .status)
 }
@@ -450,20 +387,15 @@ pub async fn create_conversations(
         })
         .collect::<Vec<_>>();
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/create-conversations"))
+    ApiRequest::post(URL, "create-conversations")
         .json(&Request {
             conversations,
             include_user_data: true,
         })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Conversations>(response).await
+        .send(client)
+        .await?
+        .json::<Conversations>()
+        .await
 }
 
 pub async fn rename_conversations(
@@ -484,23 +416,18 @@ pub async fn rename_conversations(
 
     let conversations = &ids
         .iter()
-        .zip(names.into_iter())
+        .zip(names.iter())
         .collect::<Vec<_>>()
         .iter()
         .map(|(id, name)| ConversationToUpdate { id, name })
         .collect::<Vec<_>>();
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/update-conversations"))
+    ApiRequest::post(URL, "update-conversations")
         .json(&Request { conversations })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Conversations>(response).await
+        .send(client)
+        .await?
+        .json::<Conversations>()
+        .await
 }
 
 pub async fn mark_conversations_as_read(
@@ -513,24 +440,16 @@ pub async fn mark_conversations_as_read(
         ids: &'a [&'a str],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/mark-conversations"))
-        .json(&Request { ids })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         results: Vec<ConversationMarkedStatus>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "mark-conversations")
+        .json(&Request { ids })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .results)
 }
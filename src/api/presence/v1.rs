@@ -1,9 +1,27 @@
+use std::{collections::HashMap, time::Duration};
+
 use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString, FromRepr};
 
-use crate::{Error, client::Client};
+use crate::{
+    DateTime, Error,
+    client::Client,
+    options::{self, CancellationToken},
+    request::ApiRequest,
+};
 
 pub const URL: &str = "https://presence.roblox.com/v1";
 
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display, EnumString, FromRepr)]
+pub enum PresenceType {
+    Offline = 0,
+    Online = 1,
+    InGame = 2,
+    InStudio = 3,
+    Invisible = 4,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct UserPresence {
@@ -21,6 +39,223 @@ pub struct UserPresence {
     pub job_id: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LastOnline {
+    #[serde(rename = "userId")]
+    pub id: u64,
+    pub last_online: DateTime,
+}
+
+/// A change between two polls of `PresenceWatcher`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PresenceEvent {
+    WentOnline {
+        user_id: u64,
+    },
+    JoinedGame {
+        user_id: u64,
+        place_id: Option<u64>,
+        universe_id: Option<u64>,
+        job_id: Option<String>,
+    },
+    LeftGame {
+        user_id: u64,
+        place_id: Option<u64>,
+        universe_id: Option<u64>,
+    },
+    WentOffline {
+        user_id: u64,
+    },
+}
+
+impl UserPresence {
+    /// `None` for a presence type this crate doesn't know about yet
+    pub fn presence_type(&self) -> Option<PresenceType> {
+        PresenceType::from_repr(self.kind)
+    }
+
+    // invisible is offline to everyone but the user themselves
+    pub fn is_online(&self) -> bool {
+        !matches!(
+            self.presence_type(),
+            Some(PresenceType::Offline | PresenceType::Invisible)
+        )
+    }
+
+    pub fn is_in_game(&self) -> bool {
+        self.presence_type() == Some(PresenceType::InGame)
+    }
+
+    fn is_same_game(&self, other: &UserPresence) -> bool {
+        self.place_id == other.place_id && self.job_id == other.job_id
+    }
+}
+
+/// Polls the presence of users and gives what changed since the last poll
+///
+/// ```no_run
+/// # async fn run(client: &mut roblox_api::client::Client) -> Result<(), roblox_api::Error> {
+/// use roblox_api::api::presence::v1::{PresenceEvent, PresenceWatcher};
+///
+/// let mut watcher = PresenceWatcher::new(vec![1, 156]);
+/// loop {
+///     for event in watcher.next_events(client).await? {
+///         if let PresenceEvent::JoinedGame { user_id, place_id, .. } = event {
+///             println!("{user_id} joined {place_id:?}");
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PresenceWatcher {
+    user_ids: Vec<u64>,
+    presences: HashMap<u64, UserPresence>,
+    interval: Duration,
+    chunk_size: usize,
+    cancellation: Option<CancellationToken>,
+    polled: bool,
+}
+
+impl PresenceWatcher {
+    pub fn new(user_ids: Vec<u64>) -> Self {
+        Self {
+            user_ids,
+            presences: HashMap::new(),
+            interval: Duration::from_secs(30),
+            chunk_size: 50,
+            cancellation: None,
+            polled: false,
+        }
+    }
+
+    /// How long `next_events` waits between polls, 30 seconds by default
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// How many users are asked for in one request, 50 by default
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Cancelling the token stops `next_events` with `Error::Cancelled`
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    pub fn user_ids(&self) -> &[u64] {
+        &self.user_ids
+    }
+
+    pub fn add_user(&mut self, user_id: u64) {
+        if !self.user_ids.contains(&user_id) {
+            self.user_ids.push(user_id);
+        }
+    }
+
+    pub fn remove_user(&mut self, user_id: u64) {
+        self.user_ids.retain(|x| *x != user_id);
+        self.presences.remove(&user_id);
+    }
+
+    /// The presence of a user as of the last poll
+    pub fn presence(&self, user_id: u64) -> Option<&UserPresence> {
+        self.presences.get(&user_id)
+    }
+
+    /// Polls once, the first poll only records where everyone is and gives no events
+    pub async fn poll(&mut self, client: &mut Client) -> Result<Vec<PresenceEvent>, Error> {
+        let mut presences = Vec::new();
+        for chunk in self.user_ids.clone().chunks(self.chunk_size) {
+            presences.extend(presence(client, chunk).await?);
+        }
+
+        Ok(self.update(presences))
+    }
+
+    /// Waits for the next changes, polling every interval until there are some
+    pub async fn next_events(&mut self, client: &mut Client) -> Result<Vec<PresenceEvent>, Error> {
+        loop {
+            if self.polled {
+                let wait = async {
+                    options::sleep(self.interval).await;
+                    Ok(())
+                };
+
+                match &self.cancellation {
+                    Some(token) => token.run(wait).await?,
+                    None => wait.await?,
+                }
+            }
+
+            let events = match self.cancellation.clone() {
+                Some(token) => token.run(self.poll(client)).await?,
+                None => self.poll(client).await?,
+            };
+
+            if !events.is_empty() {
+                return Ok(events);
+            }
+        }
+    }
+
+    /// Records the presences, such as ones from somewhere other than `poll`, and gives what changed
+    pub fn update(&mut self, presences: Vec<UserPresence>) -> Vec<PresenceEvent> {
+        let first = !self.polled;
+        self.polled = true;
+
+        let mut events = Vec::new();
+        for current in presences {
+            let previous = self.presences.insert(current.id, current.clone());
+            if first {
+                continue;
+            }
+
+            let Some(previous) = previous else {
+                continue;
+            };
+
+            let user_id = current.id;
+            let left = previous.is_in_game()
+                && (!current.is_in_game() || !previous.is_same_game(&current));
+            let joined = current.is_in_game()
+                && (!previous.is_in_game() || !previous.is_same_game(&current));
+
+            if !previous.is_online() && current.is_online() {
+                events.push(PresenceEvent::WentOnline { user_id });
+            }
+
+            if left {
+                events.push(PresenceEvent::LeftGame {
+                    user_id,
+                    place_id: previous.place_id,
+                    universe_id: previous.universe_id,
+                });
+            }
+
+            if joined {
+                events.push(PresenceEvent::JoinedGame {
+                    user_id,
+                    place_id: current.place_id,
+                    universe_id: current.universe_id,
+                    job_id: current.job_id.clone(),
+                });
+            }
+
+            if previous.is_online() && !current.is_online() {
+                events.push(PresenceEvent::WentOffline { user_id });
+            }
+        }
+
+        events
+    }
+}
+
 pub async fn presence(client: &mut Client, ids: &[u64]) -> Result<Vec<UserPresence>, Error> {
     #[derive(Serialize)]
     struct Request<'a> {
@@ -28,25 +263,67 @@ pub async fn presence(client: &mut Client, ids: &[u64]) -> Result<Vec<UserPresen
         users: &'a [u64],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/presence/users"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&Request { users: ids })
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "userPresences")]
         presences: Vec<UserPresence>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "presence/users")
+        .json(&Request { users: ids })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .presences)
 }
+
+pub async fn last_online(client: &mut Client, ids: &[u64]) -> Result<Vec<LastOnline>, Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        #[serde(rename = "userIds")]
+        users: &'a [u64],
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Response {
+        #[serde(rename = "lastOnlineTimestamps")]
+        timestamps: Vec<LastOnline>,
+    }
+
+    Ok(ApiRequest::post(URL, "presence/last-online")
+        .json(&Request { users: ids })
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .timestamps)
+}
+
+/// Shows the authenticated user as online in an app, such as `Home`,
+/// `disconnect` sets them back to offline
+pub async fn register_app_presence(
+    client: &mut Client,
+    location: &str,
+    place_id: Option<u64>,
+    disconnect: bool,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        location: &'a str,
+        place_id: Option<u64>,
+        disconnect: bool,
+    }
+
+    ApiRequest::post(URL, "presence/register-app-presence")
+        .json(&Request {
+            location,
+            place_id,
+            disconnect,
+        })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, api::users, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://privatemessages.roblox.com/v1";
 
@@ -70,6 +70,41 @@ pub struct Messages {
     pub current_page: u64,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SendResponse {
+    pub success: bool,
+    pub short_message: Option<String>,
+    /// Why it failed, such as the user not accepting messages
+    pub message: Option<String>,
+}
+
+impl Message {
+    /// The subject without the `RE: ` added by replies
+    pub fn base_subject(&self) -> &str {
+        let mut subject = self.subject.trim();
+        while let Some(rest) = subject
+            .strip_prefix("RE: ")
+            .or_else(|| subject.strip_prefix("Re: "))
+        {
+            subject = rest.trim_start();
+        }
+
+        subject
+    }
+
+    /// Whether both are between the same two users and have the same `base_subject`,
+    /// which is all `thread` goes by
+    pub fn is_same_thread(&self, other: &Message) -> bool {
+        let users = |x: &Message| {
+            let (a, b) = (x.sender.id, x.recipient.id);
+            (a.min(b), a.max(b))
+        };
+
+        users(self) == users(other) && self.base_subject() == other.base_subject()
+    }
+}
+
 impl std::fmt::Display for MessageTab {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -95,47 +130,31 @@ async fn generic_message_action(
         ids: &'a [u64],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/messages/{path}"))
-        .json(&Request { ids })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "failedMessages")]
         failed: Vec<u64>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, format!("messages/{path}"))
+        .json(&Request { ids })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .failed)
 }
 
 pub async fn unread_count(client: &mut Client) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/messages/unread/count"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         count: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "messages/unread/count")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .count)
 }
@@ -152,34 +171,24 @@ pub async fn messages(
         None => String::new(),
     };
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/messages"))
-        .query(&[
+    ApiRequest::get(URL, "messages")
+        .queries(&[
             ("messageTab", tab.to_string()),
             ("pageNumber", cursor),
             ("pageSize", limit),
         ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Messages>(response).await
+        .send(client)
+        .await?
+        .json::<Messages>()
+        .await
 }
 
 pub async fn announcements(client: &mut Client) -> Result<Announcements, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/announcements"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Announcements>(response).await
+    ApiRequest::get(URL, "announcements")
+        .send(client)
+        .await?
+        .json::<Announcements>()
+        .await
 }
 
 pub async fn archive(client: &mut Client, ids: &[u64]) -> Result<Vec<u64>, Error> {
@@ -197,3 +206,133 @@ pub async fn mark_as_read(client: &mut Client, ids: &[u64]) -> Result<Vec<u64>,
 pub async fn mark_as_unread(client: &mut Client, ids: &[u64]) -> Result<Vec<u64>, Error> {
     generic_message_action(client, "mark-unread", ids).await
 }
+
+pub async fn message(client: &mut Client, id: u64) -> Result<Message, Error> {
+    ApiRequest::get(URL, format!("messages/{id}"))
+        .send(client)
+        .await?
+        .json::<Message>()
+        .await
+}
+
+pub async fn can_message(client: &mut Client, user_id: u64) -> Result<bool, Error> {
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        can_message: bool,
+    }
+
+    Ok(
+        ApiRequest::get(URL, format!("messages/{user_id}/can-message"))
+            .send(client)
+            .await?
+            .json::<Response>()
+            .await?
+            .can_message,
+    )
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SendRequest<'a> {
+    user_id: u64,
+    recipient_id: u64,
+    subject: &'a str,
+    body: &'a str,
+    reply_message_id: Option<u64>,
+    include_previous_message: bool,
+}
+
+async fn send_request(
+    client: &mut Client,
+    request: &SendRequest<'_>,
+) -> Result<SendResponse, Error> {
+    ApiRequest::post(URL, "messages/send")
+        .json(request)
+        .send(client)
+        .await?
+        .json::<SendResponse>()
+        .await
+}
+
+pub async fn send(
+    client: &mut Client,
+    recipient_id: u64,
+    subject: &str,
+    body: &str,
+) -> Result<SendResponse, Error> {
+    let user = users::v1::authenticated_details(client).await?;
+
+    send_request(
+        client,
+        &SendRequest {
+            user_id: user.id,
+            recipient_id,
+            subject,
+            body,
+            reply_message_id: None,
+            include_previous_message: false,
+        },
+    )
+    .await
+}
+
+/// Replies to a message the authenticated user received,
+/// `include_previous_message` quotes the message under the reply
+pub async fn reply(
+    client: &mut Client,
+    message: &Message,
+    body: &str,
+    include_previous_message: bool,
+) -> Result<SendResponse, Error> {
+    let subject = format!("RE: {}", message.base_subject());
+
+    send_request(
+        client,
+        &SendRequest {
+            user_id: message.recipient.id,
+            recipient_id: message.sender.id,
+            subject: &subject,
+            body,
+            reply_message_id: Some(message.id),
+            include_previous_message,
+        },
+    )
+    .await
+}
+
+/// The conversation the message is in, oldest first
+///
+/// Roblox doesn't keep reply chains, so this is a guess going by `Message::is_same_thread`,
+/// unrelated messages between the same users with the same subject end up in it too.
+/// The inbox, sent and archive tabs are each read up to `max_pages` pages of 100 messages
+pub async fn thread(client: &mut Client, id: u64, max_pages: u64) -> Result<Vec<Message>, Error> {
+    let message = message(client, id).await?;
+
+    let mut thread = Vec::new();
+    for tab in [MessageTab::Inbox, MessageTab::Sent, MessageTab::Archive] {
+        let mut page = 0;
+        while page < max_pages {
+            let cursor = page.to_string();
+            let messages =
+                messages(client, tab, Paging::new(Some(&cursor), Some(100), None)).await?;
+
+            thread.extend(
+                messages
+                    .collection
+                    .into_iter()
+                    .filter(|x| x.is_same_thread(&message)),
+            );
+
+            page += 1;
+            if page >= messages.total_pages {
+                break;
+            }
+        }
+    }
+
+    // ids only go up, so they sort the same as the creation time
+    thread.sort_by_key(|x| x.id);
+    thread.dedup_by_key(|x| x.id);
+    Ok(thread)
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize, Serializer};
 use strum::{EnumIter, IntoEnumIterator};
 use strum_macros::{Display, EnumString};
 
-use crate::{Error, client::Client};
+use crate::{Error, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://thumbnails.roblox.com/v1";
 
@@ -169,6 +169,7 @@ impl ThumbnailFormat {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn generic_thumbnail_api(
     client: &mut Client,
     ids: &[u64],
@@ -207,25 +208,17 @@ async fn generic_thumbnail_api(
         query.push(("defaults", defaults.to_string()));
     }
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/{domain}"))
-        .query(&query)
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         thumbnails: Vec<ThumbnailResponse>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, domain)
+        .queries(&query)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .thumbnails)
 }
@@ -258,22 +251,14 @@ pub async fn asset_3d(
     id: u64,
     encode_gltf: bool,
 ) -> Result<ThumbnailResponse, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/assets-thumbnail-3d"))
-        .query(&[
+    ApiRequest::get(URL, "assets-thumbnail-3d")
+        .queries(&[
             ("assetId", id.to_string()),
             ("useGltf", encode_gltf.to_string()),
         ])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ThumbnailResponse>(response)
+        .send(client)
+        .await?
+        .json::<ThumbnailResponse>()
         .await
 }
 
@@ -391,6 +376,7 @@ pub async fn universe_thumbnails(
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn games(
     client: &mut Client,
     ids: &[u64],
@@ -507,19 +493,11 @@ pub async fn avatars(
 }
 
 pub async fn avatar_3d(client: &mut Client, id: u64) -> Result<ThumbnailResponse, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/avatar-3d"))
-        .query(&[("userId", id)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ThumbnailResponse>(response)
+    ApiRequest::get(URL, "avatar-3d")
+        .query("userId", id)
+        .send(client)
+        .await?
+        .json::<ThumbnailResponse>()
         .await
 }
 
@@ -568,19 +546,11 @@ pub async fn avatar_headshots(
 }
 
 pub async fn outfit_3d(client: &mut Client, id: u64) -> Result<ThumbnailResponse, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/outfit-3d"))
-        .query(&[("outfitId", id)])
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ThumbnailResponse>(response)
+    ApiRequest::get(URL, "outfit-3d")
+        .query("outfitId", id)
+        .send(client)
+        .await?
+        .json::<ThumbnailResponse>()
         .await
 }
 
@@ -610,25 +580,17 @@ pub async fn batch(
     client: &mut Client,
     requests: Vec<ThumbnailBatchRequest<'_>>,
 ) -> Result<Vec<ThumbnailResponseFromBatch>, Error> {
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/batch"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&requests)
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         thumbnails: Vec<ThumbnailResponseFromBatch>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "batch")
+        .json(&requests)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .thumbnails)
 }
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Paging, client::Client, request::ApiRequest};
+
+pub const URL: &str = "https://apis.roblox.com/token-metadata-service/v1";
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SessionLocation {
+    pub city: Option<String>,
+    pub subdivision: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SessionDevice {
+    /// Such as `Browser` or `App`
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    /// The browser or app name
+    #[serde(rename = "value")]
+    pub name: Option<String>,
+    pub os: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    /// What `revoke_session` takes, it isn't the cookie itself
+    pub token: String,
+    pub location: Option<SessionLocation>,
+    #[serde(rename = "agent")]
+    pub device: Option<SessionDevice>,
+    #[serde(rename = "lastAccessedIp")]
+    pub last_ip: Option<String>,
+    /// Unix timestamp in milliseconds
+    #[serde(rename = "lastAccessedTimestampEpochMilliseconds")]
+    pub last_seen: Option<String>,
+    #[serde(default)]
+    pub is_current_session: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Sessions {
+    pub sessions: Vec<Session>,
+    pub next_cursor: Option<String>,
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// The sort order of `paging` is ignored
+pub async fn sessions(client: &mut Client, paging: Paging<'_>) -> Result<Sessions, Error> {
+    let mut request =
+        ApiRequest::get(URL, "sessions").query("desiredLimit", paging.limit.unwrap_or(10));
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("nextCursor", cursor);
+    }
+
+    request.send(client).await?.json::<Sessions>().await
+}
+
+/// Logs out the session with the token from `sessions`
+pub async fn revoke_session(client: &mut Client, token: &str) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        token: &'a str,
+    }
+
+    ApiRequest::post(URL, "logout")
+        .json(&Request { token })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
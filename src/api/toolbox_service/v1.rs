@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{AssetTypeId, DateTime, Error, Paging, client::Client};
+use crate::{AssetTypeId, DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/toolbox-service/v1";
 
@@ -109,24 +109,17 @@ pub async fn item_details(client: &mut Client, ids: &[u64]) -> Result<Vec<ItemDe
         .collect::<Vec<String>>()
         .join(",");
 
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/items/details?assetIds={ids}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Clone, Debug, Deserialize, PartialEq)]
     struct Response {
         #[serde(rename = "data")]
         objects: Vec<ItemDetail>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "items/details")
+        .query("assetIds", ids)
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .objects)
 }
@@ -137,23 +130,12 @@ pub async fn creations(
     asset_type: AssetTypeId,
     paging: Paging<'_>,
 ) -> Result<Creations, Error> {
-    let limit = paging.limit.unwrap_or(30);
-    let cursor = match paging.cursor {
-        Some(cursor) => format!("&cursor={cursor}"),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!(
-            "{URL}/creations/user/{id}/{}?limit={limit}{cursor}",
-            asset_type as u8
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<Creations>(response).await
+    let mut request = ApiRequest::get(URL, format!("creations/user/{id}/{}", asset_type as u8))
+        .query("limit", paging.limit.unwrap_or(30));
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("cursor", cursor);
+    }
+
+    request.send(client).await?.json::<Creations>().await
 }
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
+
+pub const URL: &str = "https://trades.roblox.com/v1";
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Display)]
+pub enum TradeStatusType {
+    #[default]
+    Inbound,
+    Outbound,
+    Completed,
+    Inactive,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeUser {
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    /// The other side of the trade
+    pub user: TradeUser,
+    pub created: DateTime,
+    pub expiration: Option<DateTime>,
+    pub is_active: bool,
+    /// Such as `Open`, `Completed`, `Declined`, `Expired` or `Countered`
+    pub status: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Trades {
+    #[serde(rename = "data")]
+    pub trades: Vec<Trade>,
+    #[serde(rename = "nextPageCursor")]
+    pub next_cursor: Option<String>,
+    #[serde(rename = "previousPageCursor")]
+    pub previous_cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeAsset {
+    /// The user asset id, what `TradeOffer` takes
+    #[serde(rename = "id")]
+    pub instance_id: u64,
+    #[serde(rename = "serialNumber")]
+    pub serial: Option<u64>,
+    #[serde(rename = "assetId")]
+    pub id: u64,
+    pub name: String,
+    pub recent_average_price: u64,
+    pub original_price: Option<u64>,
+    #[serde(rename = "assetStock")]
+    pub stock: Option<u64>,
+    #[serde(rename = "membershipType")]
+    pub premium_membership_type: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOfferDetails {
+    pub user: TradeUser,
+    #[serde(rename = "userAssets")]
+    pub assets: Vec<TradeAsset>,
+    pub robux: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeDetails {
+    pub id: u64,
+    /// The authenticated user's offer comes first
+    pub offers: Vec<TradeOfferDetails>,
+    pub user: TradeUser,
+    pub created: DateTime,
+    pub expiration: Option<DateTime>,
+    pub is_active: bool,
+    pub status: String,
+}
+
+/// One side of a trade being sent, both the authenticated user and the other user need one
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeOffer {
+    pub user_id: u64,
+    /// The instance ids of the collectibles, as given by `inventory::v1::user_owned_collectibles`
+    pub user_asset_ids: Vec<u64>,
+    pub robux: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CanTradeWith {
+    pub can_trade: bool,
+    /// Why it can't, such as `CannotTradeWithSelf` or `UnknownUser`
+    pub status: String,
+}
+
+pub async fn trades(
+    client: &mut Client,
+    status: TradeStatusType,
+    paging: Paging<'_>,
+) -> Result<Trades, Error> {
+    ApiRequest::get(URL, format!("trades/{status}"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Trades>()
+        .await
+}
+
+pub async fn trade_count(client: &mut Client, status: TradeStatusType) -> Result<u64, Error> {
+    #[derive(Deserialize)]
+    struct Response {
+        count: u64,
+    }
+
+    Ok(ApiRequest::get(URL, format!("trades/{status}/count"))
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?
+        .count)
+}
+
+pub async fn details(client: &mut Client, id: u64) -> Result<TradeDetails, Error> {
+    ApiRequest::get(URL, format!("trades/{id}"))
+        .send(client)
+        .await?
+        .json::<TradeDetails>()
+        .await
+}
+
+pub async fn can_trade_with(client: &mut Client, user_id: u64) -> Result<CanTradeWith, Error> {
+    ApiRequest::get(URL, format!("users/{user_id}/can-trade-with"))
+        .send(client)
+        .await?
+        .json::<CanTradeWith>()
+        .await
+}
+
+#[derive(Serialize)]
+struct OffersRequest<'a> {
+    offers: &'a [TradeOffer],
+}
+
+#[derive(Deserialize)]
+struct TradeIdResponse {
+    id: u64,
+}
+
+/// Returns the id of the new trade
+pub async fn send(client: &mut Client, offers: &[TradeOffer]) -> Result<u64, Error> {
+    Ok(ApiRequest::post(URL, "trades/send")
+        .json(&OffersRequest { offers })
+        .send(client)
+        .await?
+        .json::<TradeIdResponse>()
+        .await?
+        .id)
+}
+
+/// Declines the trade and sends back a new one, returns its id
+pub async fn counter(client: &mut Client, id: u64, offers: &[TradeOffer]) -> Result<u64, Error> {
+    Ok(ApiRequest::post(URL, format!("trades/{id}/counter"))
+        .json(&OffersRequest { offers })
+        .send(client)
+        .await?
+        .json::<TradeIdResponse>()
+        .await?
+        .id)
+}
+
+/// Usually challenged with two step verification, see `Client::set_challenge_solver`
+pub async fn accept(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("trades/{id}/accept"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn decline(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("trades/{id}/decline"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
@@ -1,9 +1,79 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, challenge::ActionType, client::Client};
+use crate::{DateTime, Error, challenge::ActionType, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://twostepverification.roblox.com/v1";
 
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum MediaType {
+    Email,
+    #[serde(rename = "SMS")]
+    Sms,
+    Authenticator,
+    RecoveryCode,
+    SecurityKey,
+    Passkey,
+    CrossDevice,
+    Password,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    #[serde(rename = "twoStepVerificationEnabled")]
+    pub is_enabled: bool,
+    pub authenticator_qr_code_size: Option<String>,
+    pub email_code_length: u8,
+    pub authenticator_code_length: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Method {
+    pub media_type: MediaType,
+    #[serde(rename = "enabled")]
+    pub is_enabled: bool,
+    pub updated: Option<DateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Configuration {
+    pub primary_media_type: Option<MediaType>,
+    pub methods: Vec<Method>,
+}
+
+/// The WebAuthn options for the security key or passkey, the credential made from them
+/// is passed on to the finish call along with the session id
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialChallenge {
+    pub session_id: String,
+    // a json string
+    pub authentication_options: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSetup {
+    pub setup_token: String,
+    pub qr_code_image_url: String,
+    pub manual_entry_key: String,
+}
+
+fn media_path(media: MediaType) -> &'static str {
+    match media {
+        MediaType::Email => "email",
+        MediaType::Sms => "sms",
+        MediaType::Authenticator => "authenticator",
+        MediaType::RecoveryCode => "recovery-codes",
+        MediaType::SecurityKey => "security-key",
+        MediaType::Passkey => "passkey",
+        MediaType::CrossDevice => "cross-device",
+        MediaType::Password => "password",
+    }
+}
+
 // TODO: I don't know what `user_id` is for, as this api only seems to be used for the client only,
 // there's also currently no way to require id from Client, perhaps we should authenticate
 // on from_cookie method, and store the ClientDetails in the Client
@@ -13,6 +83,50 @@ pub async fn authenticator_verify(
     code: &str,
     action_type: ActionType,
     server_challenge_id: &str,
+) -> Result<String, Error> {
+    verify(
+        client,
+        user_id,
+        "authenticator",
+        code,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+async fn send_code(
+    client: &mut Client,
+    user_id: u64,
+    media: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        action_type: &'a str,
+        challenge_id: &'a str,
+    }
+
+    ApiRequest::post(URL, format!("users/{user_id}/challenges/{media}/send-code"))
+        .json(&Request {
+            action_type: &action_type.to_string(),
+            challenge_id: server_challenge_id,
+        })
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+async fn verify(
+    client: &mut Client,
+    user_id: u64,
+    media: &str,
+    code: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
 ) -> Result<String, Error> {
     #[derive(Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -28,23 +142,389 @@ pub async fn authenticator_verify(
         verification_token: String,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!(
-            "{URL}/users/{user_id}/challenges/authenticator/verify"
-        ))
-        .headers(client.requestor.default_headers.clone())
+    let result = ApiRequest::post(URL, format!("users/{user_id}/challenges/{media}/verify"))
         .json(&Request {
             action_type: &action_type.to_string(),
             challenge_id: server_challenge_id,
             code,
         })
-        .send()
-        .await;
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
+
+    Ok(result.verification_token)
+}
+
+pub(crate) async fn code_verify(
+    client: &mut Client,
+    user_id: u64,
+    media: MediaType,
+    code: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    verify(
+        client,
+        user_id,
+        media_path(media),
+        code,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+/// Sends the code to the email of the user
+pub async fn email_send_code(
+    client: &mut Client,
+    user_id: u64,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<(), Error> {
+    send_code(client, user_id, "email", action_type, server_challenge_id).await
+}
+
+pub async fn email_verify(
+    client: &mut Client,
+    user_id: u64,
+    code: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    verify(
+        client,
+        user_id,
+        "email",
+        code,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+/// Sends the code to the phone number of the user
+pub async fn sms_send_code(
+    client: &mut Client,
+    user_id: u64,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<(), Error> {
+    send_code(client, user_id, "sms", action_type, server_challenge_id).await
+}
+
+pub async fn sms_verify(
+    client: &mut Client,
+    user_id: u64,
+    code: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    verify(
+        client,
+        user_id,
+        "sms",
+        code,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+pub async fn recovery_code_verify(
+    client: &mut Client,
+    user_id: u64,
+    code: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    verify(
+        client,
+        user_id,
+        "recovery-codes",
+        code,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+async fn credential_verify_start(
+    client: &mut Client,
+    user_id: u64,
+    media: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<CredentialChallenge, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        action_type: &'a str,
+        challenge_id: &'a str,
+    }
+
+    ApiRequest::post(
+        URL,
+        format!("users/{user_id}/challenges/{media}/verify-start"),
+    )
+    .json(&Request {
+        action_type: &action_type.to_string(),
+        challenge_id: server_challenge_id,
+    })
+    .send(client)
+    .await?
+    .json::<CredentialChallenge>()
+    .await
+}
 
-    let response = client.requestor.validate_response(result).await?;
-    let result = client.requestor.parse_json::<Response>(response).await?;
+async fn credential_verify_finish(
+    client: &mut Client,
+    user_id: u64,
+    media: &str,
+    session_id: &str,
+    credential: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        action_type: &'a str,
+        challenge_id: &'a str,
+        session_id: &'a str,
+        credential: &'a str,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        verification_token: String,
+    }
+
+    let result = ApiRequest::post(
+        URL,
+        format!("users/{user_id}/challenges/{media}/verify-finish"),
+    )
+    .json(&Request {
+        action_type: &action_type.to_string(),
+        challenge_id: server_challenge_id,
+        session_id,
+        credential,
+    })
+    .send(client)
+    .await?
+    .json::<Response>()
+    .await?;
 
     Ok(result.verification_token)
 }
+
+pub async fn security_key_verify_start(
+    client: &mut Client,
+    user_id: u64,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<CredentialChallenge, Error> {
+    credential_verify_start(
+        client,
+        user_id,
+        "security-key",
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+/// `credential` is the json of the WebAuthn credential, made from `security_key_verify_start`'s options
+pub async fn security_key_verify_finish(
+    client: &mut Client,
+    user_id: u64,
+    session_id: &str,
+    credential: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    credential_verify_finish(
+        client,
+        user_id,
+        "security-key",
+        session_id,
+        credential,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+pub async fn passkey_verify_start(
+    client: &mut Client,
+    user_id: u64,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<CredentialChallenge, Error> {
+    credential_verify_start(client, user_id, "passkey", action_type, server_challenge_id).await
+}
+
+/// `credential` is the json of the WebAuthn credential, made from `passkey_verify_start`'s options
+pub async fn passkey_verify_finish(
+    client: &mut Client,
+    user_id: u64,
+    session_id: &str,
+    credential: &str,
+    action_type: ActionType,
+    server_challenge_id: &str,
+) -> Result<String, Error> {
+    credential_verify_finish(
+        client,
+        user_id,
+        "passkey",
+        session_id,
+        credential,
+        action_type,
+        server_challenge_id,
+    )
+    .await
+}
+
+/// `server_challenge_id` and `action_type` are only needed while solving a challenge
+pub async fn metadata(
+    client: &mut Client,
+    user_id: u64,
+    server_challenge_id: Option<&str>,
+    action_type: Option<ActionType>,
+) -> Result<Metadata, Error> {
+    let mut request = ApiRequest::get(URL, "metadata").query("userId", user_id);
+
+    if let Some(server_challenge_id) = server_challenge_id {
+        request = request.query("challengeId", server_challenge_id);
+    }
+
+    if let Some(action_type) = action_type {
+        request = request.query("actionType", action_type);
+    }
+
+    request.send(client).await?.json::<Metadata>().await
+}
+
+/// The methods the user has and which one is used by default
+pub async fn configuration(
+    client: &mut Client,
+    user_id: u64,
+    server_challenge_id: Option<&str>,
+    action_type: Option<ActionType>,
+) -> Result<Configuration, Error> {
+    let mut request = ApiRequest::get(URL, format!("users/{user_id}/configuration"));
+
+    if let Some(server_challenge_id) = server_challenge_id {
+        request = request.query("challengeId", server_challenge_id);
+    }
+
+    if let Some(action_type) = action_type {
+        request = request.query("actionType", action_type);
+    }
+
+    request.send(client).await?.json::<Configuration>().await
+}
+
+/// Enables email, sms or security key 2SV, the authenticator needs to be set up with
+/// `authenticator_enable` instead
+pub async fn enable(
+    client: &mut Client,
+    user_id: u64,
+    media: MediaType,
+    password: &str,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        password: &'a str,
+    }
+
+    ApiRequest::post(
+        URL,
+        format!("users/{user_id}/configuration/{}/enable", media_path(media)),
+    )
+    .json(&Request { password })
+    .send(client)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn disable(
+    client: &mut Client,
+    user_id: u64,
+    media: MediaType,
+    password: &str,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Request<'a> {
+        password: &'a str,
+    }
+
+    ApiRequest::post(
+        URL,
+        format!(
+            "users/{user_id}/configuration/{}/disable",
+            media_path(media)
+        ),
+    )
+    .json(&Request { password })
+    .send(client)
+    .await?;
+
+    Ok(())
+}
+
+/// Starts setting up an authenticator app, finished with `authenticator_enable_verify`
+pub async fn authenticator_enable(
+    client: &mut Client,
+    user_id: u64,
+) -> Result<AuthenticatorSetup, Error> {
+    ApiRequest::post(
+        URL,
+        format!("users/{user_id}/configuration/authenticator/enable"),
+    )
+    .send(client)
+    .await?
+    .json::<AuthenticatorSetup>()
+    .await
+}
+
+/// Returns the recovery codes of the account
+pub async fn authenticator_enable_verify(
+    client: &mut Client,
+    user_id: u64,
+    setup_token: &str,
+    code: &str,
+    password: &str,
+) -> Result<Vec<String>, Error> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Request<'a> {
+        setup_token: &'a str,
+        code: &'a str,
+        password: &'a str,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        recovery_codes: Vec<String>,
+    }
+
+    let result = ApiRequest::post(
+        URL,
+        format!("users/{user_id}/configuration/authenticator/enable-verify"),
+    )
+    .json(&Request {
+        setup_token,
+        code,
+        password,
+    })
+    .send(client)
+    .await?
+    .json::<Response>()
+    .await?;
+
+    Ok(result.recovery_codes)
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, client::Client};
+use crate::{Error, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/user-agreements/v1";
 
@@ -30,26 +30,18 @@ pub async fn acceptances(
 
     let acceptances = ids.iter().map(|x| Agreement { id: x }).collect::<Vec<_>>();
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/acceptances"))
-        .json(&Request {
-            acceptances: &acceptances,
-        })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         results: Vec<AcceptanceResponse>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "acceptances")
+        .json(&Request {
+            acceptances: &acceptances,
+        })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .results)
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Error, client::Client};
+use crate::{Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://apis.roblox.com/user-blocking-api/v1";
 
@@ -13,17 +13,29 @@ pub struct UserBlockStatus {
     pub is_blocking_viewer: bool,
 }
 
-pub async fn is_blocked(client: &mut Client, id: u64) -> Result<bool, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}/is-blocked"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockedUser {
+    #[serde(rename = "userId")]
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
 
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<bool>(response).await
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BlockedUsers {
+    #[serde(rename = "blockedUsers")]
+    pub users: Vec<BlockedUser>,
+    #[serde(rename = "cursor")]
+    pub next_cursor: Option<String>,
+}
+
+pub async fn is_blocked(client: &mut Client, id: u64) -> Result<bool, Error> {
+    ApiRequest::get(URL, format!("users/{id}/is-blocked"))
+        .send(client)
+        .await?
+        .json::<bool>()
+        .await
 }
 
 pub async fn batch_check_reciprocal_block(
@@ -39,24 +51,49 @@ pub async fn batch_check_reciprocal_block(
         ids: &'a [u64],
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/users/batch-check-reciprocal-block"))
-        .json(&Request { requester_id, ids })
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         users: Vec<UserBlockStatus>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "users/batch-check-reciprocal-block")
+        .json(&Request { requester_id, ids })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .users)
 }
+
+/// Also unfriends and unfollows the user
+pub async fn block_user(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/block-user"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn unblock_user(client: &mut Client, id: u64) -> Result<(), Error> {
+    ApiRequest::post(URL, format!("users/{id}/unblock-user"))
+        .send(client)
+        .await?;
+
+    Ok(())
+}
+
+/// The users the authenticated user has blocked, the sort order of `paging` is ignored
+pub async fn blocked_users(client: &mut Client, paging: Paging<'_>) -> Result<BlockedUsers, Error> {
+    #[derive(Debug, Deserialize)]
+    struct Response {
+        data: BlockedUsers,
+    }
+
+    let mut request =
+        ApiRequest::get(URL, "users/get-blocked-users").query("count", paging.limit.unwrap_or(50));
+    if let Some(cursor) = paging.cursor {
+        request = request.query("cursor", cursor);
+    }
+
+    Ok(request.send(client).await?.json::<Response>().await?.data)
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString, FromRepr};
 
-use crate::{DateTime, Error, Paging, client::Client};
+use crate::{DateTime, Error, Paging, client::Client, request::ApiRequest};
 
 pub const URL: &str = "https://users.roblox.com/v1";
 
@@ -98,16 +98,11 @@ pub struct UserSearchByKeyword {
 }
 
 pub async fn user_details(client: &mut Client, id: u64) -> Result<UserDetails, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/{id}"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<UserDetails>(response).await
+    ApiRequest::get(URL, format!("users/{id}"))
+        .send(client)
+        .await?
+        .json::<UserDetails>()
+        .await
 }
 
 pub async fn user_username_history(
@@ -115,23 +110,6 @@ pub async fn user_username_history(
     id: u64,
     paging: Paging<'_>,
 ) -> Result<NameHistory, Error> {
-    let limit = paging.limit.unwrap_or(10);
-    let sort_order = paging.order.unwrap_or_default().to_string();
-    let cursor = match paging.cursor {
-        Some(cursor) => format!("&cursor={cursor}"),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!(
-            "{URL}/users/{id}/username-history?limit={limit}&sortOrder={sort_order}{cursor}"
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     // no they don't have anything else in here
     #[derive(Debug, Deserialize)]
     struct Username {
@@ -148,8 +126,12 @@ pub async fn user_username_history(
         previous_cursor: Option<String>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let result = client.requestor.parse_json::<Response>(response).await?;
+    let result = ApiRequest::get(URL, format!("users/{id}/username-history"))
+        .paging(paging, 10)
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     let names = result.names.iter().map(|x| x.name.clone()).collect();
     Ok(NameHistory {
@@ -172,28 +154,20 @@ pub async fn users_by_id(
         exclude_terminated: bool,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/users"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&Request {
-            ids,
-            exclude_terminated,
-        })
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         users: Vec<UserById>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "users")
+        .json(&Request {
+            ids,
+            exclude_terminated,
+        })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .users)
 }
@@ -211,28 +185,20 @@ pub async fn users_by_name(
         exclude_terminated: bool,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/usernames/users"))
-        .headers(client.requestor.default_headers.clone())
-        .json(&Request {
-            names,
-            exclude_terminated,
-        })
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "data")]
         names: Vec<UserByName>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::post(URL, "usernames/users")
+        .json(&Request {
+            names,
+            exclude_terminated,
+        })
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .names)
 }
@@ -244,111 +210,73 @@ pub async fn search_by_keyword(
     session_id: Option<&str>,
     paging: Paging<'_>,
 ) -> Result<UserSearchByKeyword, Error> {
-    let limit = paging.limit.unwrap_or(10);
-    let cursor = match paging.cursor {
-        Some(cursor) => format!("&cursor={cursor}"),
-        None => String::new(),
-    };
-
-    let session_id = match session_id {
-        Some(session_id) => format!("&session_id={session_id}"),
-        None => String::new(),
-    };
-
-    let result = client
-        .requestor
-        .client
-        .get(format!(
-            "{URL}/users/search?keyword={keyword}&limit={limit}{cursor}{session_id}"
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<UserSearchByKeyword>(response)
+    let mut request = ApiRequest::get(URL, "users/search")
+        .query("keyword", keyword)
+        .query("limit", paging.limit.unwrap_or(10));
+
+    if let Some(cursor) = paging.cursor {
+        request = request.query("cursor", cursor);
+    }
+
+    if let Some(session_id) = session_id {
+        request = request.query("session_id", session_id);
+    }
+
+    request
+        .send(client)
+        .await?
+        .json::<UserSearchByKeyword>()
         .await
 }
 
 pub async fn authenticated_details(client: &mut Client) -> Result<ClientDetails, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/authenticated"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client.requestor.parse_json::<ClientDetails>(response).await
+    ApiRequest::get(URL, "users/authenticated")
+        .send(client)
+        .await?
+        .json::<ClientDetails>()
+        .await
 }
 
 pub async fn authenticated_age_bracket(client: &mut Client) -> Result<u64, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/authenticated/age-bracket"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
     struct Response {
         age_bracket: u64,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "users/authenticated/age-bracket")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .age_bracket)
 }
 
 pub async fn authenticated_country_code(client: &mut Client) -> Result<String, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/authenticated/country-code"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     #[serde(rename_all = "camelCase")]
     struct Response {
         country_code: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "users/authenticated/country-code")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .country_code)
 }
 
 pub async fn authenticated_roles(client: &mut Client) -> Result<Vec<String>, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/authenticated/roles"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         roles: Vec<String>,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    Ok(client
-        .requestor
-        .parse_json::<Response>(response)
+    Ok(ApiRequest::get(URL, "users/authenticated/roles")
+        .send(client)
+        .await?
+        .json::<Response>()
         .await?
         .roles)
 }
@@ -356,30 +284,14 @@ pub async fn authenticated_roles(client: &mut Client) -> Result<Vec<String>, Err
 pub async fn authenticated_app_launch_info(
     client: &mut Client,
 ) -> Result<ClientAppLaunchInfo, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/users/authenticated/app-launch-info"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    let response = client.requestor.validate_response(result).await?;
-    client
-        .requestor
-        .parse_json::<ClientAppLaunchInfo>(response)
+    ApiRequest::get(URL, "users/authenticated/app-launch-info")
+        .send(client)
+        .await?
+        .json::<ClientAppLaunchInfo>()
         .await
 }
 
 pub async fn birthdate(client: &mut Client) -> Result<DateTime, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/birthdate"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "birthDay")]
@@ -390,8 +302,11 @@ pub async fn birthdate(client: &mut Client) -> Result<DateTime, Error> {
         year: i32,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let birthdate = client.requestor.parse_json::<Response>(response).await?;
+    let birthdate = ApiRequest::get(URL, "birthdate")
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     Ok(DateTime::from_ymd(
         birthdate.year,
@@ -418,41 +333,30 @@ pub async fn set_birthdate(
         //pub password: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/birthdate"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, "birthdate")
         .json(&Request {
             day: birthdate.day(),
             month: birthdate.month(),
             year: birthdate.year(),
             //password,
         })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
 
 pub async fn description(client: &mut Client) -> Result<String, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/description"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "description")]
         value: String,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let description = client.requestor.parse_json::<Response>(response).await?;
+    let description = ApiRequest::get(URL, "description")
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     Ok(description.value)
 }
@@ -464,36 +368,25 @@ pub async fn set_description(client: &mut Client, description: &str) -> Result<(
         value: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/description"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, "description")
         .json(&Request { value: description })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
 
 pub async fn gender(client: &mut Client) -> Result<Gender, Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!("{URL}/gender"))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
     #[derive(Debug, Deserialize)]
     struct Response {
         #[serde(rename = "gender")]
         value: u8,
     }
 
-    let response = client.requestor.validate_response(result).await?;
-    let gender: Response = client.requestor.parse_json(response).await?;
+    let gender = ApiRequest::get(URL, "gender")
+        .send(client)
+        .await?
+        .json::<Response>()
+        .await?;
 
     Ok(Gender::from_repr(gender.value).expect("failed to parse gender"))
 }
@@ -505,18 +398,12 @@ pub async fn set_gender(client: &mut Client, gender: Gender) -> Result<(), Error
         value: u8,
     }
 
-    let result = client
-        .requestor
-        .client
-        .post(format!("{URL}/gender"))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::post(URL, "gender")
         .json(&Request {
             value: gender as u8,
         })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
 
@@ -525,18 +412,11 @@ pub async fn validate_display_name(
     display_name: &str,
     birthdate: DateTime,
 ) -> Result<(), Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!(
-            "{URL}/display-names/validate?displayName={display_name}&birthdate={}",
-            birthdate
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+    ApiRequest::get(URL, "display-names/validate")
+        .query("displayName", display_name)
+        .query("birthdate", birthdate)
+        .send(client)
+        .await?;
     Ok(())
 }
 
@@ -548,17 +428,10 @@ pub async fn validate_display_name_by_id(
     id: u64,
     display_name: &str,
 ) -> Result<(), Error> {
-    let result = client
-        .requestor
-        .client
-        .get(format!(
-            "{URL}/users/{id}/display-names/validate?displayName={display_name}"
-        ))
-        .headers(client.requestor.default_headers.clone())
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+    ApiRequest::get(URL, format!("users/{id}/display-names/validate"))
+        .query("displayName", display_name)
+        .send(client)
+        .await?;
     Ok(())
 }
 
@@ -576,17 +449,10 @@ pub async fn set_display_name(
         display_name: &'a str,
     }
 
-    let result = client
-        .requestor
-        .client
-        .patch(format!(
-            "{URL}/users/{id}/display-names?displayName={display_name}"
-        ))
-        .headers(client.requestor.default_headers.clone())
+    ApiRequest::patch(URL, format!("users/{id}/display-names"))
+        .query("displayName", display_name)
         .json(&Request { display_name })
-        .send()
-        .await;
-
-    client.requestor.validate_response(result).await?;
+        .send(client)
+        .await?;
     Ok(())
 }
@@ -1,5 +1,5 @@
 use base64::{Engine, prelude::BASE64_STANDARD};
-use reqwest::header::HeaderValue;
+use reqwest::header::{HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -12,6 +12,11 @@ pub(crate) const CHALLENGE_ID_HEADER: &str = "rblx-challenge-id";
 pub(crate) const CHALLENGE_TYPE_HEADER: &str = "rblx-challenge-type";
 pub(crate) const CHALLENGE_METADATA_HEADER: &str = "rblx-challenge-metadata";
 
+// captcha and chef challenges are answered with the lowercase names roblox gives them,
+// unlike the generic ones which keep sending `ChallengeType`'s own names
+pub(crate) const CAPTCHA_CHALLENGE_TYPE: &str = "captcha";
+pub(crate) const CHEF_CHALLENGE_TYPE: &str = "chef";
+
 #[derive(Clone, Copy, Default, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub enum ActionType {
     #[default]
@@ -90,6 +95,18 @@ pub struct ChefChallengeMetadata {
     pub script_identifiers: Vec<String>,
 }
 
+impl ChallengeMetadata {
+    pub fn user_id(&self) -> Result<u64, Error> {
+        self.user_id.parse().map_err(|_| Error::BadJson)
+    }
+}
+
+impl ChefChallengeMetadata {
+    pub fn user_id(&self) -> Result<u64, Error> {
+        self.user_id.parse().map_err(|_| Error::BadJson)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Challenge {
     pub id: String,
@@ -97,6 +114,48 @@ pub struct Challenge {
     pub metadata: ChallengeMetadata,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptchaChallengeMetadata {
+    pub unified_captcha_id: String,
+    // passed on to the captcha provider
+    #[serde(rename = "dataExchangeBlob")]
+    pub blob: String,
+    pub action_type: ActionType,
+}
+
+/// Has to be solved externally, the token is then submitted with `Client::queue_captcha_challenge`
+/// or given by `ChallengeSolver::solve_captcha`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CaptchaChallenge {
+    pub id: String,
+    pub metadata: CaptchaChallengeMetadata,
+}
+
+/// Has to be solved by running the challenge's scripts, the result is then submitted
+/// with `Client::queue_chef_challenge` or given by `ChallengeSolver::solve_chef`
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ChefChallenge {
+    pub id: String,
+    pub metadata: ChefChallengeMetadata,
+}
+
+/// What `ApiError::ChallengeRequired` holds, depending on the type of the challenge
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RequiredChallenge {
+    Generic(Challenge),
+    Captcha(CaptchaChallenge),
+    Chef(ChefChallenge),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CaptchaChallengeMetadataRequest {
+    pub(crate) unified_captcha_id: String,
+    pub(crate) captcha_token: String,
+    pub(crate) action_type: ActionType,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ChallengeMetadataRequest {
@@ -108,7 +167,7 @@ pub(crate) struct ChallengeMetadataRequest {
 
 impl Client {
     // the name is misleading, there's no queue, also this function is kinda ugly to use,
-    // perhaps it should be reworked
+    // `Client::set_challenge_solver` does all of this by itself
     pub async fn queue_challenge(
         &mut self,
         challenge: &Challenge,
@@ -120,42 +179,105 @@ impl Client {
             .queue_challenge(challenge, verification_token)
             .await
     }
+
+    /// Submits a captcha token obtained for the challenge, the challenged request can then be sent again
+    pub async fn queue_captcha_challenge(
+        &mut self,
+        challenge: &CaptchaChallenge,
+        captcha_token: &str,
+    ) -> Result<(), Error> {
+        challenge::v1::continue_captcha_challenge(self, challenge, captcha_token).await?;
+        self.requestor
+            .queue_captcha_challenge(challenge, captcha_token)
+    }
+
+    /// Submits the result of the challenge's scripts, the challenged request can then be sent again,
+    /// `metadata` is sent as is
+    pub async fn queue_chef_challenge(
+        &mut self,
+        challenge: &ChefChallenge,
+        metadata: &serde_json::Value,
+    ) -> Result<(), Error> {
+        challenge::v1::continue_chef_challenge(self, challenge, metadata).await?;
+        self.requestor.queue_chef_challenge(challenge, metadata)
+    }
 }
 
 impl ClientRequestor {
-    async fn queue_challenge(
+    pub(crate) async fn queue_challenge(
         &mut self,
         challenge: &Challenge,
         verification_token: &str,
     ) -> Result<(), Error> {
-        self.default_headers.insert(
-            CHALLENGE_ID_HEADER,
-            HeaderValue::from_str(&challenge.id).unwrap(),
-        );
+        let metadata = serde_json::to_vec(
+            &(ChallengeMetadataRequest {
+                verification_token: verification_token.to_string(),
+                challenge_id: challenge.metadata.server_challenge_id.clone(),
+                action_type: challenge.metadata.action_type,
+                remember_device: challenge.metadata.remember_device,
+            }),
+        )
+        .unwrap();
 
-        self.default_headers.insert(
-            CHALLENGE_TYPE_HEADER,
-            HeaderValue::from_str(&challenge.kind.to_string()).unwrap(),
-        );
+        self.set_challenge_headers(&challenge.id, &challenge.kind.to_string(), &metadata);
+        Ok(())
+    }
 
-        let metadata_b64 = BASE64_STANDARD.encode(
-            serde_json::to_vec(
-                &(ChallengeMetadataRequest {
-                    verification_token: verification_token.to_string(),
-                    challenge_id: challenge.metadata.server_challenge_id.clone(),
-                    action_type: challenge.metadata.action_type,
-                    remember_device: challenge.metadata.remember_device,
-                }),
-            )
-            .unwrap(),
+    pub(crate) fn queue_captcha_challenge(
+        &mut self,
+        challenge: &CaptchaChallenge,
+        captcha_token: &str,
+    ) -> Result<(), Error> {
+        let metadata = serde_json::to_vec(&CaptchaChallengeMetadataRequest {
+            unified_captcha_id: challenge.metadata.unified_captcha_id.clone(),
+            captcha_token: captcha_token.to_string(),
+            action_type: challenge.metadata.action_type,
+        })?;
+
+        self.set_challenge_headers(&challenge.id, CAPTCHA_CHALLENGE_TYPE, &metadata);
+        Ok(())
+    }
+
+    pub(crate) fn queue_chef_challenge(
+        &mut self,
+        challenge: &ChefChallenge,
+        metadata: &serde_json::Value,
+    ) -> Result<(), Error> {
+        self.set_challenge_headers(
+            &challenge.id,
+            CHEF_CHALLENGE_TYPE,
+            &serde_json::to_vec(metadata)?,
         );
+        Ok(())
+    }
+
+    fn set_challenge_headers(&mut self, id: &str, kind: &str, metadata: &[u8]) {
+        self.default_headers
+            .insert(CHALLENGE_ID_HEADER, HeaderValue::from_str(id).unwrap());
+
+        self.default_headers
+            .insert(CHALLENGE_TYPE_HEADER, HeaderValue::from_str(kind).unwrap());
 
         self.default_headers.insert(
             CHALLENGE_METADATA_HEADER,
-            HeaderValue::from_str(&metadata_b64).unwrap(),
+            HeaderValue::from_str(&BASE64_STANDARD.encode(metadata)).unwrap(),
         );
+    }
 
-        Ok(())
+    /// Takes the queued challenge headers off the client, to be put on the challenged request itself
+    pub(crate) fn take_challenge(&mut self) -> Vec<(HeaderName, HeaderValue)> {
+        [
+            CHALLENGE_ID_HEADER,
+            CHALLENGE_TYPE_HEADER,
+            CHALLENGE_METADATA_HEADER,
+        ]
+        .into_iter()
+        .filter_map(|name| {
+            self.default_headers
+                .remove(name)
+                .map(|value| (HeaderName::from_static(name), value))
+        })
+        .collect()
     }
 
     pub(crate) fn remove_challenge(&mut self) {
@@ -1,31 +1,86 @@
 use reqwest::{
-    Method, Response,
+    Method,
     header::{self, HeaderMap, HeaderValue},
 };
-use serde::{Serialize, de::DeserializeOwned};
 
-use crate::{Error, ratelimit::Ratelimit};
+use crate::{
+    Error,
+    hba::{self, HbaKey},
+    oauth::OAuthSession,
+    options::CallOptions,
+    ratelimit::Ratelimit,
+    request::{ApiRequest, ApiResponse, RequestBody, RequestPlan},
+    solver::ChallengeSolver,
+};
 
-#[derive(Default)]
+const API_KEY_HEADER: &str = "x-api-key";
+
+#[derive(Clone, Default, PartialEq, Eq)]
 pub struct Cookie(String);
 
+// keeps the session out of logs, such as a printed `LoginState`
+impl std::fmt::Debug for Cookie {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Cookie").field(&"<redacted>").finish()
+    }
+}
+
 impl std::fmt::Display for Cookie {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+impl Cookie {
+    /// The value of `.ROBLOSECURITY`, what `Cookie::from` takes
+    pub fn value(&self) -> &str {
+        self.0.strip_prefix(".ROBLOSECURITY=").unwrap_or(&self.0)
+    }
+}
+
 impl From<&str> for Cookie {
     fn from(value: &str) -> Self {
         Self(format!(".ROBLOSECURITY={}", value))
     }
 }
 
+/// An Open Cloud api key
+#[derive(Default)]
+pub struct ApiKey(String);
+
+impl std::fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AuthKind {
+    #[default]
+    Cookie,
+    ApiKey,
+    OAuth,
+}
+
 #[derive(Default, Debug)]
 pub struct ClientRequestor {
     pub(crate) client: reqwest::Client,
     pub(crate) default_headers: HeaderMap,
+    pub(crate) auth: AuthKind,
+    pub(crate) oauth: Option<OAuthSession>,
     pub(crate) ratelimit: Option<Ratelimit>,
+    pub(crate) options: Option<CallOptions>,
+    pub(crate) dry_run: bool,
+    pub(crate) plans: Vec<RequestPlan>,
+    pub(crate) solver: Option<Box<dyn ChallengeSolver>>,
+    pub(crate) hba_key: Option<HbaKey>,
+    pub(crate) services: Vec<(String, String)>,
 }
 
 #[derive(Default, Debug)]
@@ -58,11 +113,100 @@ impl Client {
             requestor: ClientRequestor {
                 client,
                 default_headers,
+                auth: AuthKind::Cookie,
+                oauth: None,
+                ratelimit: None,
+                options: None,
+                dry_run: false,
+                plans: Vec::new(),
+                solver: None,
+                hba_key: None,
+                services: Vec::new(),
+            },
+        }
+    }
+
+    /// Only endpoints that have an Open Cloud variant can be used with an api key or oauth token,
+    /// the rest fail with `Error::CookieRequired`
+    pub fn from_api_key(key: ApiKey) -> Self {
+        let client = reqwest::Client::new();
+        let mut default_headers = HeaderMap::new();
+
+        default_headers.insert(
+            header::USER_AGENT,
+            HeaderValue::from_str("Roblox/WinInet").unwrap(),
+        );
+
+        default_headers.insert(
+            API_KEY_HEADER,
+            HeaderValue::from_str(&key.to_string()).unwrap(),
+        );
+
+        Client {
+            requestor: ClientRequestor {
+                client,
+                default_headers,
+                auth: AuthKind::ApiKey,
+                oauth: None,
                 ratelimit: None,
+                options: None,
+                dry_run: false,
+                plans: Vec::new(),
+                solver: None,
+                hba_key: None,
+                services: Vec::new(),
             },
         }
     }
 
+    /// The session cookie, `None` once logged out
+    pub fn cookie(&self) -> Option<Cookie> {
+        self.requestor
+            .default_headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|x| x.to_str().ok())
+            .find(|x| x.starts_with(".ROBLOSECURITY=") && x.len() > ".ROBLOSECURITY=".len())
+            .map(|x| Cookie(x.to_string()))
+    }
+
+    /// Swaps the session cookie, such as for the one given when logging out of all other sessions
+    pub fn set_cookie(&mut self, cookie: Cookie) {
+        self.clear_cookie();
+        self.requestor.default_headers.append(
+            header::COOKIE,
+            HeaderValue::from_str(&cookie.to_string()).unwrap(),
+        );
+    }
+
+    pub fn clear_cookie(&mut self) {
+        let headers = &mut self.requestor.default_headers;
+        let cookies: Vec<HeaderValue> = headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter(|x| !x.as_bytes().starts_with(b".ROBLOSECURITY="))
+            .cloned()
+            .collect();
+
+        headers.remove(header::COOKIE);
+        for cookie in cookies {
+            headers.append(header::COOKIE, cookie);
+        }
+    }
+
+    /// Sends requests meant for `service`, such as `users::v1::URL`, to `url` instead,
+    /// like a proxy or a local server in tests
+    pub fn set_service_url(&mut self, service: &str, url: &str) {
+        self.requestor.services.retain(|(x, _)| x != service);
+        self.requestor
+            .services
+            .push((service.to_string(), url.to_string()));
+    }
+
+    pub fn auth_kind(&self) -> AuthKind {
+        self.requestor.auth
+    }
+
     pub async fn ensure_token(&mut self) -> Result<(), Error> {
         self.requestor.ensure_token().await
     }
@@ -71,59 +215,106 @@ impl Client {
         self.requestor.ratelimits().await
     }
 
+    /// While enabled, anything that isn't a GET request is recorded instead of being sent,
+    /// and the call fails with `Error::DryRun` holding what would have been sent
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.requestor.dry_run = enabled;
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.requestor.dry_run
+    }
+
+    /// The requests recorded while in dry-run
+    pub fn plans(&self) -> &[RequestPlan] {
+        &self.requestor.plans
+    }
+
+    pub fn take_plans(&mut self) -> Vec<RequestPlan> {
+        std::mem::take(&mut self.requestor.plans)
+    }
+
     // TODO: test if account is terminated
     // TODO: add reactivate account function
     // pub async fn test_account_status() {}
 }
 
-pub(crate) struct ResponseWrapped(Response);
-impl ResponseWrapped {
-    pub(crate) async fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
-        Ok(self.0.json::<T>().await?)
+impl ClientRequestor {
+    pub(crate) async fn send(&mut self, mut request: ApiRequest) -> Result<ApiResponse, Error> {
+        // options set on the client only last for one request, even one that never gets sent
+        request.resolve_options(self.options.take());
+
+        if self.auth != AuthKind::Cookie && !request.is_open_cloud() {
+            return Err(Error::CookieRequired);
+        }
+
+        if self.dry_run && request.method() != Method::GET {
+            let request = self.route(request);
+            let built = request
+                .build(&self.client, &self.default_headers)?
+                .build()?;
+            let plan = RequestPlan {
+                method: request.method().to_string(),
+                url: built.url().to_string(),
+                body: match request.body() {
+                    RequestBody::Json(json) => Some(json.clone()),
+                    RequestBody::Form(pairs) => Some(serde_json::Value::Object(
+                        pairs
+                            .iter()
+                            .map(|(key, value)| (key.clone(), value.clone().into()))
+                            .collect(),
+                    )),
+                    _ => None,
+                },
+            };
+
+            self.plans.push(plan.clone());
+            return Err(Error::DryRun(plan));
+        }
+
+        // after the dry-run check, so a dry-run never sends a token request
+        if self.oauth.as_ref().is_some_and(|x| x.is_expiring()) {
+            self.refresh_oauth().await?;
+        }
+
+        self.execute(request).await
     }
 
-    pub(crate) async fn bytes(self) -> Result<Vec<u8>, Error> {
-        let bytes = self.0.bytes().await;
-        match bytes {
-            Ok(bytes) => Ok(bytes.to_vec()),
-            Err(error) => Err(Error::ReqwestError(error)),
+    fn route(&self, mut request: ApiRequest) -> ApiRequest {
+        if let Some((_, url)) = self.services.iter().find(|(x, _)| x == request.service()) {
+            request.set_service(url);
         }
+
+        request
     }
-}
 
-impl ClientRequestor {
-    pub(crate) async fn parse_json<T: DeserializeOwned>(
-        &self,
-        response: Response,
-    ) -> Result<T, Error> {
-        Ok(response.json::<T>().await?)
-    }
-
-    pub(crate) async fn request<'a, R: Serialize>(
-        &mut self,
-        method: Method,
-        url: &str,
-        request: Option<&'a R>,
-        query: Option<&'a [(&'a str, &'a str)]>,
-        headers: Option<HeaderMap>,
-    ) -> Result<ResponseWrapped, Error> {
-        // TODO: use builder outside for this, so we don't need the 3 optionals
-
-        let mut builder = self
-            .client
-            .request(method, url)
-            .headers(headers.unwrap_or(self.default_headers.clone()));
-
-        // Even though sending None works, it might get serialized as null in json, which is a waste of bytes
-        if let Some(request) = request {
-            builder = builder.json(&request);
+    pub(crate) async fn execute(&mut self, request: ApiRequest) -> Result<ApiResponse, Error> {
+        let request = self.route(request);
+        let options = request.call_options().clone();
+
+        let mut builder = request.build(&self.client, &self.default_headers)?;
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
         }
 
-        if let Some(query) = query {
-            builder = builder.query(&query);
+        let mut built = builder.build()?;
+        if let Some(key) = &self.hba_key
+            && self.auth == AuthKind::Cookie
+            && !request.is_open_cloud()
+        {
+            hba::sign_request(key, &mut built);
         }
 
-        let response = self.validate_response(builder.send().await).await?;
-        Ok(ResponseWrapped(response))
+        let client = self.client.clone();
+        let response = match &options.cancellation {
+            Some(token) => {
+                token
+                    .run(async { self.validate_response(client.execute(built).await).await })
+                    .await?
+            }
+            None => self.validate_response(client.execute(built).await).await?,
+        };
+
+        Ok(ApiResponse::new(response))
     }
 }
@@ -0,0 +1,116 @@
+use std::time::SystemTime;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use p256::{
+    ecdsa::{Signature, SigningKey, signature::Signer},
+    elliptic_curve::rand_core::OsRng,
+    pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey},
+};
+use reqwest::header::HeaderValue;
+use sha2::{Digest, Sha256};
+
+use crate::client::Client;
+
+pub(crate) const BOUND_AUTH_TOKEN_HEADER: &str = "x-bound-auth-token";
+
+/// The key a session gets bound to on login (hardware backed auth), requests made with the
+/// session are signed with it, so it has to be kept along with the cookie
+#[derive(Clone, Debug)]
+pub struct HbaKey(SigningKey);
+
+impl Default for HbaKey {
+    fn default() -> Self {
+        Self::generate()
+    }
+}
+
+impl PartialEq for HbaKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bytes() == other.0.to_bytes()
+    }
+}
+
+impl Eq for HbaKey {}
+
+impl HbaKey {
+    pub fn generate() -> Self {
+        Self(SigningKey::random(&mut OsRng))
+    }
+
+    /// Imports a key saved with `HbaKey::export`
+    pub fn import(key: &str) -> Option<Self> {
+        let der = BASE64_STANDARD.decode(key.trim()).ok()?;
+        SigningKey::from_pkcs8_der(&der).ok().map(Self)
+    }
+
+    /// The private key as base64 PKCS#8
+    pub fn export(&self) -> String {
+        BASE64_STANDARD.encode(self.0.to_pkcs8_der().unwrap().as_bytes())
+    }
+
+    /// The public key as base64 SPKI, which is what roblox expects
+    pub fn public_key(&self) -> String {
+        BASE64_STANDARD.encode(
+            self.0
+                .verifying_key()
+                .to_public_key_der()
+                .unwrap()
+                .as_bytes(),
+        )
+    }
+
+    /// Signs with ECDSA P-256 SHA-256, the signature is the raw `r || s` in base64
+    pub fn sign(&self, message: &[u8]) -> String {
+        let signature: Signature = self.0.sign(message);
+        BASE64_STANDARD.encode(signature.to_bytes())
+    }
+
+    /// The value of the `x-bound-auth-token` header for a request
+    pub fn bound_auth_token(&self, url: &str, method: &str, body: &[u8]) -> String {
+        let unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        self.bound_auth_token_at(unix, url, method, body)
+    }
+
+    pub fn bound_auth_token_at(&self, unix: u64, url: &str, method: &str, body: &[u8]) -> String {
+        let body_hash = BASE64_STANDARD.encode(Sha256::digest(body));
+
+        // the second signature leaves the body out, for requests that get their body changed on the way
+        let signature = self.sign(format!("{body_hash}|{unix}|{url}|{method}").as_bytes());
+        let signature_without_body = self.sign(format!("|{unix}|{url}|{method}").as_bytes());
+
+        format!("v1|{body_hash}|{unix}|{signature}|{signature_without_body}")
+    }
+}
+
+impl Client {
+    /// Once set, every cookie request carries a bound auth token signed with the key
+    pub fn set_hba_key(&mut self, key: HbaKey) {
+        self.requestor.hba_key = Some(key);
+    }
+
+    pub fn hba_key(&self) -> Option<&HbaKey> {
+        self.requestor.hba_key.as_ref()
+    }
+
+    /// The key a login binds the session to, one is generated if the client has none
+    pub(crate) fn hba_key_or_generate(&mut self) -> &HbaKey {
+        self.requestor.hba_key.get_or_insert_with(HbaKey::generate)
+    }
+}
+
+pub(crate) fn sign_request(key: &HbaKey, request: &mut reqwest::Request) {
+    let body = request
+        .body()
+        .and_then(|x| x.as_bytes())
+        .unwrap_or_default();
+
+    let token = key.bound_auth_token(request.url().as_str(), request.method().as_str(), body);
+    request.headers_mut().insert(
+        BOUND_AUTH_TOKEN_HEADER,
+        HeaderValue::from_str(&token).unwrap(),
+    );
+}
@@ -1,10 +1,17 @@
 pub mod api;
 pub mod challenge;
 pub mod client;
+pub mod hba;
+pub mod login;
+pub mod oauth;
+pub mod options;
 pub mod ratelimit;
+pub mod request;
+pub mod solver;
+pub mod totp;
 pub mod validation;
 
-use challenge::Challenge;
+use challenge::RequiredChallenge;
 use chrono::{Datelike, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString, FromRepr};
@@ -42,6 +49,12 @@ impl DateTime {
 pub enum Error {
     ApiError(ApiError),
     BadJson,
+    Cancelled,
+    /// The endpoint has no Open Cloud variant, so it can't be used with an api key or oauth token
+    CookieRequired,
+    DryRun(request::RequestPlan),
+    /// What was being waited on expired first, such as a quick login code
+    Expired,
     IoError(std::io::Error),
     ReqwestError(reqwest::Error),
     #[cfg(feature = "web-socket")]
@@ -64,7 +77,7 @@ pub enum ApiError {
     PinIsLocked,
     TokenValidation,
     CaptchaFailed,
-    ChallengeRequired(Challenge),
+    ChallengeRequired(RequiredChallenge),
     ChallengeFailed,
     InvalidChallengeId,
     InvalidTwoStepVerificationCode,
@@ -0,0 +1,409 @@
+use reqwest::header::{HeaderName, HeaderValue};
+
+use crate::{
+    ApiError, Error,
+    api::{
+        auth::{
+            self,
+            v1::{LoginResponse, LoginType},
+        },
+        two_step_verification::{
+            self,
+            v1::{CredentialChallenge, MediaType},
+        },
+    },
+    challenge::{ActionType, ChallengeType, RequiredChallenge},
+    client::{Client, Cookie},
+};
+
+/// Where the login is at, each state says what has to be done next
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoginState {
+    /// Solve it, such as with `Client::queue_captcha_challenge`, then call `LoginFlow::start` again
+    ChallengeRequired(RequiredChallenge),
+    /// Pick one of the user's methods with `LoginFlow::select_method`
+    MethodRequired {
+        methods: Vec<MediaType>,
+        primary: Option<MediaType>,
+    },
+    /// Enter the code with `LoginFlow::submit_code`
+    CodeRequired(MediaType),
+    /// Sign the options with the security key or passkey, then `LoginFlow::submit_credential`
+    CredentialRequired(CredentialChallenge),
+    /// The cookie is also set on the client
+    LoggedIn { user_id: u64, cookie: Cookie },
+}
+
+#[derive(Clone)]
+struct TwoStepVerification {
+    user_id: u64,
+    challenge_id: String,
+    media_type: Option<MediaType>,
+    session_id: Option<String>,
+}
+
+/// Drives a login through challenges and two step verification
+///
+/// ```no_run
+/// # async fn run(client: &mut roblox_api::client::Client) -> Result<(), roblox_api::Error> {
+/// use roblox_api::login::{LoginFlow, LoginState};
+///
+/// let mut flow = LoginFlow::username("Roblox", "password");
+/// let mut state = flow.start(client).await?;
+/// let cookie = loop {
+///     state = match state {
+///         LoginState::ChallengeRequired(_) => todo!("solve the challenge"),
+///         LoginState::MethodRequired { methods, .. } => {
+///             flow.select_method(client, methods[0]).await?
+///         }
+///         LoginState::CodeRequired(_) => flow.submit_code(client, "123456").await?,
+///         LoginState::CredentialRequired(_) => todo!("sign with the security key"),
+///         LoginState::LoggedIn { cookie, .. } => break cookie,
+///     };
+/// };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LoginFlow {
+    login_type: LoginType,
+    login: String,
+    key: String,
+    remember_device: bool,
+    two_step_verification: Option<TwoStepVerification>,
+}
+
+// the password and the login ticket stay out of logs
+impl std::fmt::Debug for TwoStepVerification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TwoStepVerification")
+            .field("user_id", &self.user_id)
+            .field("challenge_id", &"<redacted>")
+            .field("media_type", &self.media_type)
+            .field(
+                "session_id",
+                &self.session_id.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for LoginFlow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoginFlow")
+            .field("login_type", &self.login_type)
+            .field("login", &self.login)
+            .field("key", &"<redacted>")
+            .field("remember_device", &self.remember_device)
+            .field("two_step_verification", &self.two_step_verification)
+            .finish()
+    }
+}
+
+impl LoginFlow {
+    pub fn new(login_type: LoginType, login: &str, key: &str) -> Self {
+        Self {
+            login_type,
+            login: login.to_string(),
+            key: key.to_string(),
+            remember_device: false,
+            two_step_verification: None,
+        }
+    }
+
+    pub fn username(username: &str, password: &str) -> Self {
+        Self::new(LoginType::Username, username, password)
+    }
+
+    pub fn email(email: &str, password: &str) -> Self {
+        Self::new(LoginType::Email, email, password)
+    }
+
+    pub fn phone_number(phone_number: &str, password: &str) -> Self {
+        Self::new(LoginType::PhoneNumber, phone_number, password)
+    }
+
+    /// Logs in with a quick login code, `private_key` is the one given with the code
+    pub fn auth_token(code: &str, private_key: &str) -> Self {
+        Self::new(LoginType::AuthToken, code, private_key)
+    }
+
+    pub fn remember_device(mut self, remember_device: bool) -> Self {
+        self.remember_device = remember_device;
+        self
+    }
+
+    pub async fn start(&mut self, client: &mut Client) -> Result<LoginState, Error> {
+        self.two_step_verification = None;
+
+        // any request clears a solved challenge from the client, and the nonce
+        // and csrf requests go before the login, so the login carries it itself
+        let challenge = client.requestor.take_challenge();
+
+        let mut result = self.send_login(client, &challenge).await;
+
+        // the first request only gets us the csrf token
+        if let Err(Error::ApiError(ApiError::TokenValidation)) = result {
+            result = self.send_login(client, &challenge).await;
+        }
+
+        let (response, cookie) = match result {
+            Ok(result) => result,
+
+            Err(Error::ApiError(ApiError::ChallengeRequired(RequiredChallenge::Generic(
+                challenge,
+            )))) if challenge.kind == ChallengeType::TwoStepVerification => {
+                self.two_step_verification = Some(TwoStepVerification {
+                    user_id: challenge.metadata.user_id()?,
+                    challenge_id: challenge.metadata.server_challenge_id.clone(),
+                    media_type: None,
+                    session_id: None,
+                });
+
+                return self.methods(client).await;
+            }
+
+            Err(Error::ApiError(ApiError::ChallengeRequired(challenge))) => {
+                return Ok(LoginState::ChallengeRequired(challenge));
+            }
+
+            Err(error) => return Err(error),
+        };
+
+        if let Some(info) = response.two_step_verification_info {
+            self.two_step_verification = Some(TwoStepVerification {
+                user_id: response.user.id,
+                challenge_id: info.ticket,
+                media_type: None,
+                session_id: None,
+            });
+
+            return self.methods(client).await;
+        }
+
+        match cookie {
+            Some(cookie) => {
+                client.set_cookie(cookie.clone());
+                Ok(LoginState::LoggedIn {
+                    user_id: response.user.id,
+                    cookie,
+                })
+            }
+            None => Err(Error::ApiError(ApiError::Unauthorized)),
+        }
+    }
+
+    /// Sends the code for email and sms, or starts the security key and passkey verification
+    pub async fn select_method(
+        &mut self,
+        client: &mut Client,
+        media_type: MediaType,
+    ) -> Result<LoginState, Error> {
+        let state = self.state()?;
+        let (user_id, challenge_id) = (state.user_id, state.challenge_id.clone());
+
+        let next = match media_type {
+            MediaType::Email => {
+                two_step_verification::v1::email_send_code(
+                    client,
+                    user_id,
+                    ActionType::Login,
+                    &challenge_id,
+                )
+                .await?;
+                LoginState::CodeRequired(media_type)
+            }
+
+            MediaType::Sms => {
+                two_step_verification::v1::sms_send_code(
+                    client,
+                    user_id,
+                    ActionType::Login,
+                    &challenge_id,
+                )
+                .await?;
+                LoginState::CodeRequired(media_type)
+            }
+
+            MediaType::Authenticator | MediaType::RecoveryCode => {
+                LoginState::CodeRequired(media_type)
+            }
+
+            MediaType::SecurityKey | MediaType::Passkey => {
+                let challenge = if media_type == MediaType::SecurityKey {
+                    two_step_verification::v1::security_key_verify_start(
+                        client,
+                        user_id,
+                        ActionType::Login,
+                        &challenge_id,
+                    )
+                    .await?
+                } else {
+                    two_step_verification::v1::passkey_verify_start(
+                        client,
+                        user_id,
+                        ActionType::Login,
+                        &challenge_id,
+                    )
+                    .await?
+                };
+
+                self.state_mut()?.session_id = Some(challenge.session_id.clone());
+                LoginState::CredentialRequired(challenge)
+            }
+
+            // these can't be used to log in
+            MediaType::CrossDevice | MediaType::Password => {
+                return Err(Error::ApiError(ApiError::BadRequest));
+            }
+        };
+
+        self.state_mut()?.media_type = Some(media_type);
+        Ok(next)
+    }
+
+    pub async fn submit_code(
+        &mut self,
+        client: &mut Client,
+        code: &str,
+    ) -> Result<LoginState, Error> {
+        let state = self.state()?.clone();
+        let media_type = match state.media_type {
+            Some(
+                media_type @ (MediaType::Email
+                | MediaType::Sms
+                | MediaType::Authenticator
+                | MediaType::RecoveryCode),
+            ) => media_type,
+            _ => return Err(Error::ApiError(ApiError::BadRequest)),
+        };
+
+        let verification_token = two_step_verification::v1::code_verify(
+            client,
+            state.user_id,
+            media_type,
+            code,
+            ActionType::Login,
+            &state.challenge_id,
+        )
+        .await?;
+
+        self.finish(client, &verification_token).await
+    }
+
+    /// `credential` is the json of the WebAuthn credential
+    pub async fn submit_credential(
+        &mut self,
+        client: &mut Client,
+        credential: &str,
+    ) -> Result<LoginState, Error> {
+        let state = self.state()?.clone();
+        let session_id = state.session_id.unwrap_or_default();
+
+        let verification_token = match state.media_type {
+            Some(MediaType::SecurityKey) => {
+                two_step_verification::v1::security_key_verify_finish(
+                    client,
+                    state.user_id,
+                    &session_id,
+                    credential,
+                    ActionType::Login,
+                    &state.challenge_id,
+                )
+                .await?
+            }
+
+            Some(MediaType::Passkey) => {
+                two_step_verification::v1::passkey_verify_finish(
+                    client,
+                    state.user_id,
+                    &session_id,
+                    credential,
+                    ActionType::Login,
+                    &state.challenge_id,
+                )
+                .await?
+            }
+
+            _ => return Err(Error::ApiError(ApiError::BadRequest)),
+        };
+
+        self.finish(client, &verification_token).await
+    }
+
+    async fn send_login(
+        &self,
+        client: &mut Client,
+        challenge: &[(HeaderName, HeaderValue)],
+    ) -> Result<(LoginResponse, Option<Cookie>), Error> {
+        let mut request =
+            auth::v1::login_request(client, &self.login, &self.key, self.login_type.clone())
+                .await?;
+
+        for (name, value) in challenge {
+            request = request.header(name.clone(), value.clone());
+        }
+
+        let response = request.send(client).await?;
+
+        let cookie = auth::v3::cookie_from_headers(response.headers());
+        Ok((response.json::<LoginResponse>().await?, cookie))
+    }
+
+    async fn methods(&self, client: &mut Client) -> Result<LoginState, Error> {
+        let state = self.state()?;
+        let configuration = two_step_verification::v1::configuration(
+            client,
+            state.user_id,
+            Some(&state.challenge_id),
+            Some(ActionType::Login),
+        )
+        .await?;
+
+        Ok(LoginState::MethodRequired {
+            methods: configuration
+                .methods
+                .into_iter()
+                .filter(|x| x.is_enabled)
+                .map(|x| x.media_type)
+                .collect(),
+            primary: configuration.primary_media_type,
+        })
+    }
+
+    async fn finish(
+        &mut self,
+        client: &mut Client,
+        verification_token: &str,
+    ) -> Result<LoginState, Error> {
+        let state = self.state()?.clone();
+        let cookie = auth::v3::two_step_verification_login(
+            client,
+            state.user_id,
+            &state.challenge_id,
+            verification_token,
+            self.remember_device,
+        )
+        .await?;
+
+        self.two_step_verification = None;
+        client.set_cookie(cookie.clone());
+        Ok(LoginState::LoggedIn {
+            user_id: state.user_id,
+            cookie,
+        })
+    }
+
+    // calling the two step verification functions before `start` asked for it is a mistake
+    fn state(&self) -> Result<&TwoStepVerification, Error> {
+        self.two_step_verification
+            .as_ref()
+            .ok_or(Error::ApiError(ApiError::BadRequest))
+    }
+
+    fn state_mut(&mut self) -> Result<&mut TwoStepVerification, Error> {
+        self.two_step_verification
+            .as_mut()
+            .ok_or(Error::ApiError(ApiError::BadRequest))
+    }
+}
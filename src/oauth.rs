@@ -0,0 +1,98 @@
+use std::time::{Duration, Instant};
+
+use reqwest::header::{self, HeaderMap, HeaderValue};
+
+use crate::{
+    Error,
+    api::oauth::v1::{self as oauth, OAuthConfig, TokenResponse},
+    client::{AuthKind, Client, ClientRequestor},
+};
+
+// refresh a bit before the token actually expires, so it doesn't run out mid request
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub(crate) struct OAuthSession {
+    config: OAuthConfig,
+    tokens: TokenResponse,
+    expires_at: Instant,
+}
+
+impl OAuthSession {
+    fn new(config: OAuthConfig, tokens: TokenResponse) -> Self {
+        Self {
+            expires_at: Instant::now() + Duration::from_secs(tokens.expires_in),
+            config,
+            tokens,
+        }
+    }
+
+    pub(crate) fn is_expiring(&self) -> bool {
+        Instant::now() + EXPIRY_MARGIN >= self.expires_at
+    }
+}
+
+// sensitive, so the default headers don't show it when the client is debug printed
+fn bearer(tokens: &TokenResponse) -> HeaderValue {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", tokens.access_token)).unwrap();
+    value.set_sensitive(true);
+    value
+}
+
+impl Client {
+    /// The access token is refreshed with the refresh token whenever it's about to expire,
+    /// only endpoints that have an Open Cloud variant can be used with it
+    pub fn from_oauth_token(config: OAuthConfig, tokens: TokenResponse) -> Self {
+        let mut default_headers = HeaderMap::new();
+
+        default_headers.insert(
+            header::USER_AGENT,
+            HeaderValue::from_str("Roblox/WinInet").unwrap(),
+        );
+
+        default_headers.insert(header::AUTHORIZATION, bearer(&tokens));
+
+        Client {
+            requestor: ClientRequestor {
+                client: reqwest::Client::new(),
+                default_headers,
+                auth: AuthKind::OAuth,
+                oauth: Some(OAuthSession::new(config, tokens)),
+                ratelimit: None,
+                options: None,
+                dry_run: false,
+                plans: Vec::new(),
+                solver: None,
+                hba_key: None,
+                services: Vec::new(),
+            },
+        }
+    }
+
+    /// The current tokens, the refresh token changes on every refresh so it should be saved from here
+    pub fn oauth_tokens(&self) -> Option<&TokenResponse> {
+        self.requestor.oauth.as_ref().map(|x| &x.tokens)
+    }
+
+    pub async fn refresh_oauth_token(&mut self) -> Result<(), Error> {
+        self.requestor.refresh_oauth().await
+    }
+}
+
+impl ClientRequestor {
+    pub(crate) async fn refresh_oauth(&mut self) -> Result<(), Error> {
+        let Some(session) = &self.oauth else {
+            return Ok(());
+        };
+
+        let request = oauth::refresh_request(&session.config, &session.tokens.refresh_token);
+        let tokens = self.execute(request).await?.json::<TokenResponse>().await?;
+        self.default_headers
+            .insert(header::AUTHORIZATION, bearer(&tokens));
+
+        let session = self.oauth.take().unwrap();
+        self.oauth = Some(OAuthSession::new(session.config, tokens));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    future::{Future, poll_fn},
+    pin::pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use crate::{Error, client::Client};
+
+/// Options for a single call, either passed to `ApiRequest::options`
+/// or set on the client with `Client::with_options` before calling a wrapped endpoint
+///
+/// ```no_run
+/// # async fn run(client: &mut roblox_api::client::Client) -> Result<(), roblox_api::Error> {
+/// use std::time::Duration;
+/// use roblox_api::{api::users, options::CallOptions};
+///
+/// let options = CallOptions::new().timeout(Duration::from_secs(5));
+/// let user = users::v1::user_details(client.with_options(options), 1).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CallOptions {
+    pub timeout: Option<Duration>,
+    pub cancellation: Option<CancellationToken>,
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Cancels every call it was given to, clones share the same state
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<CancellationState>);
+
+impl CallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Used by endpoints that dedupe requests, such as purchases, so retrying with the
+    /// same key can't go through twice, endpoints that don't support one ignore it
+    pub fn idempotency_key(mut self, key: &str) -> Self {
+        self.idempotency_key = Some(key.to_string());
+        self
+    }
+
+    /// Fills in anything unset from `other`
+    pub(crate) fn or(self, other: Option<CallOptions>) -> Self {
+        let Some(other) = other else {
+            return self;
+        };
+
+        Self {
+            timeout: self.timeout.or(other.timeout),
+            cancellation: self.cancellation.or(other.cancellation),
+            idempotency_key: self.idempotency_key.or(other.idempotency_key),
+        }
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        for waker in self.0.wakers.lock().unwrap().drain(..) {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Runs `future` until it finishes or the token gets cancelled
+    pub(crate) async fn run<T>(
+        &self,
+        future: impl Future<Output = Result<T, Error>>,
+    ) -> Result<T, Error> {
+        let mut future = pin!(future);
+        poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(Err(Error::Cancelled));
+            }
+
+            if let Poll::Ready(result) = future.as_mut().poll(cx) {
+                return Poll::Ready(result);
+            }
+
+            let mut wakers = self.0.wakers.lock().unwrap();
+            if !wakers.iter().any(|x| x.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+            drop(wakers);
+
+            // it could have been cancelled before the waker got registered
+            if self.is_cancelled() {
+                return Poll::Ready(Err(Error::Cancelled));
+            }
+
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+// the crate doesn't depend on any async runtime, so the timer gets its own thread
+pub(crate) async fn sleep(duration: Duration) {
+    let state = Arc::new((AtomicBool::new(false), Mutex::new(None::<Waker>)));
+
+    {
+        let state = state.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            state.0.store(true, Ordering::SeqCst);
+            if let Some(waker) = state.1.lock().unwrap().take() {
+                waker.wake();
+            }
+        });
+    }
+
+    poll_fn(|cx| {
+        if state.0.load(Ordering::SeqCst) {
+            return Poll::Ready(());
+        }
+
+        *state.1.lock().unwrap() = Some(cx.waker().clone());
+
+        // it could have finished before the waker got stored
+        if state.0.load(Ordering::SeqCst) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+impl Client {
+    /// Applies `options` to the next request made with this client
+    pub fn with_options(&mut self, options: CallOptions) -> &mut Self {
+        self.requestor.options = Some(options);
+        self
+    }
+}
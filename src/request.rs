@@ -0,0 +1,450 @@
+use std::path::Path;
+
+use reqwest::{
+    Method, Response,
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    multipart::{Form, Part},
+};
+use serde::{Serialize, de::DeserializeOwned};
+use uuid::Uuid;
+
+use crate::{
+    ApiError, Error, Paging, client::Client, options::CallOptions, solver::ChallengeSolver,
+};
+
+/// What the endpoint is expected to answer with, used to pick the `Accept` header
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ResponseKind {
+    #[default]
+    Json,
+    Bytes,
+    Text,
+    Empty,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultipartField {
+    Text {
+        name: String,
+        value: String,
+    },
+    File {
+        name: String,
+        file_name: String,
+        mime: String,
+        bytes: Vec<u8>,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum RequestBody {
+    #[default]
+    Empty,
+    Json(serde_json::Value),
+    Form(Vec<(String, String)>),
+    Multipart(Vec<MultipartField>),
+    Raw(Vec<u8>),
+}
+
+/// A request against a roblox service, every api function is built on top of this,
+/// it can also be used to call endpoints that aren't wrapped yet
+///
+/// ```no_run
+/// # async fn run(client: &mut roblox_api::client::Client) -> Result<(), roblox_api::Error> {
+/// use roblox_api::{api::users, request::ApiRequest};
+///
+/// let bytes = ApiRequest::get(users::v1::URL, "users/1")
+///     .send(client)
+///     .await?
+///     .bytes()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ApiRequest {
+    method: Method,
+    service: String,
+    path: String,
+    query: Vec<(String, String)>,
+    body: RequestBody,
+    headers: HeaderMap,
+    response_kind: ResponseKind,
+    options: CallOptions,
+    open_cloud: bool,
+    idempotency_field: Option<String>,
+    // set when the json body couldn't be serialized, `build` fails with it
+    invalid_body: bool,
+}
+
+/// A request that was recorded instead of being sent, see `Client::set_dry_run`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RequestPlan {
+    pub method: String,
+    /// The full url, including the query
+    pub url: String,
+    pub body: Option<serde_json::Value>,
+}
+
+pub struct ApiResponse(Response);
+
+// puts the solver back on the client, even when the call is dropped midway
+struct SolverGuard<'a> {
+    client: &'a mut Client,
+    solver: Option<Box<dyn ChallengeSolver>>,
+}
+
+impl Drop for SolverGuard<'_> {
+    fn drop(&mut self) {
+        self.client.requestor.solver = self.solver.take();
+    }
+}
+
+impl MultipartField {
+    pub fn text(name: &str, value: impl Into<String>) -> Self {
+        Self::Text {
+            name: name.to_string(),
+            value: value.into(),
+        }
+    }
+
+    /// Reads the file at `path`, the mime type is guessed from the extension
+    pub fn file(name: &str, path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(Error::IoError)?;
+
+        Ok(Self::File {
+            name: name.to_string(),
+            file_name: path
+                .file_name()
+                .map(|x| x.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            mime: mime_guess::from_path(path)
+                .first_or_octet_stream()
+                .to_string(),
+            bytes,
+        })
+    }
+}
+
+impl ApiRequest {
+    /// `service` is the base url of the api, such as `users::v1::URL`, `path` is relative to it
+    pub fn new(method: Method, service: &str, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            service: service.to_string(),
+            path: path.into(),
+            query: Vec::new(),
+            body: RequestBody::Empty,
+            headers: HeaderMap::new(),
+            response_kind: ResponseKind::default(),
+            options: CallOptions::default(),
+            open_cloud: false,
+            idempotency_field: None,
+            invalid_body: false,
+        }
+    }
+
+    pub fn get(service: &str, path: impl Into<String>) -> Self {
+        Self::new(Method::GET, service, path)
+    }
+
+    pub fn post(service: &str, path: impl Into<String>) -> Self {
+        Self::new(Method::POST, service, path)
+    }
+
+    pub fn patch(service: &str, path: impl Into<String>) -> Self {
+        Self::new(Method::PATCH, service, path)
+    }
+
+    pub fn put(service: &str, path: impl Into<String>) -> Self {
+        Self::new(Method::PUT, service, path)
+    }
+
+    pub fn delete(service: &str, path: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, service, path)
+    }
+
+    pub fn query(mut self, key: &str, value: impl ToString) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn queries<V: ToString>(mut self, pairs: &[(&str, V)]) -> Self {
+        for (key, value) in pairs {
+            self.query.push((key.to_string(), value.to_string()));
+        }
+
+        self
+    }
+
+    /// Adds the `limit`, `sortOrder` and `cursor` parameters most paged apis take,
+    /// `cursor` is left out when there is none
+    pub fn paging(self, paging: Paging<'_>, default_limit: u16) -> Self {
+        let request = self
+            .query("limit", paging.limit.unwrap_or(default_limit))
+            .query("sortOrder", paging.order.unwrap_or_default());
+
+        match paging.cursor {
+            Some(cursor) => request.query("cursor", cursor),
+            None => request,
+        }
+    }
+
+    /// A body that can't be serialized, such as a map with non-string keys,
+    /// fails the call with `Error::BadJson`
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Self {
+        match serde_json::to_value(body) {
+            Ok(json) => {
+                self.body = RequestBody::Json(json);
+                self.invalid_body = false;
+            }
+            Err(_) => self.invalid_body = true,
+        }
+
+        self
+    }
+
+    /// Sends the pairs url encoded as `application/x-www-form-urlencoded`
+    pub fn form<V: ToString>(mut self, pairs: &[(&str, V)]) -> Self {
+        self.body = RequestBody::Form(
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        self.invalid_body = false;
+
+        self
+    }
+
+    pub fn multipart(mut self, fields: Vec<MultipartField>) -> Self {
+        self.body = RequestBody::Multipart(fields);
+        self.invalid_body = false;
+        self
+    }
+
+    pub fn raw(mut self, bytes: Vec<u8>) -> Self {
+        self.body = RequestBody::Raw(bytes);
+        self.invalid_body = false;
+        self
+    }
+
+    /// Sets a header for this call only, overriding the client's default one of the same name
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn expect(mut self, kind: ResponseKind) -> Self {
+        self.response_kind = kind;
+        self
+    }
+
+    /// Takes priority over the options set with `Client::with_options`
+    pub fn options(mut self, options: CallOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Puts the idempotency key of the call options in the json body as `field`, a random
+    /// one is made if none was set, it's kept for replays of this request but not other calls
+    pub fn idempotency_key(mut self, field: &str) -> Self {
+        self.idempotency_field = Some(field.to_string());
+        self
+    }
+
+    /// Marks the endpoint as accepting Open Cloud api keys, anything else is refused
+    /// for clients made with `Client::from_api_key`
+    pub fn open_cloud(mut self) -> Self {
+        self.open_cloud = true;
+        self
+    }
+
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    pub(crate) fn set_service(&mut self, service: &str) {
+        self.service = service.to_string();
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The full url without the query, an empty path is the service url itself
+    pub fn url(&self) -> String {
+        if self.path.is_empty() {
+            return self.service.clone();
+        }
+
+        format!("{}/{}", self.service, self.path)
+    }
+
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    pub fn body(&self) -> &RequestBody {
+        &self.body
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn response_kind(&self) -> ResponseKind {
+        self.response_kind
+    }
+
+    pub fn call_options(&self) -> &CallOptions {
+        &self.options
+    }
+
+    pub fn is_open_cloud(&self) -> bool {
+        self.open_cloud
+    }
+
+    /// If the client has a challenge solver, challenged requests are solved and sent again
+    pub async fn send(self, client: &mut Client) -> Result<ApiResponse, Error> {
+        let mut request = self;
+        request.resolve_options(client.requestor.options.take());
+
+        // taken out while solving, so the solver's own requests aren't solved again
+        let Some(solver) = client.requestor.solver.take() else {
+            return client.requestor.send(request).await;
+        };
+
+        let guard = SolverGuard {
+            client,
+            solver: Some(solver),
+        };
+
+        let result = guard.client.requestor.send(request.clone()).await;
+        let Err(Error::ApiError(ApiError::ChallengeRequired(challenge))) = result else {
+            return result;
+        };
+
+        let solver = guard.solver.as_deref().unwrap();
+        guard.client.solve_challenge(solver, &challenge).await?;
+        guard.client.requestor.send(request).await
+    }
+
+    /// Merges in the options set on the client and settles the idempotency key,
+    /// doing it again is a no-op so replays send the same key
+    pub(crate) fn resolve_options(&mut self, client_options: Option<CallOptions>) {
+        self.options = std::mem::take(&mut self.options).or(client_options);
+
+        let Some(field) = &self.idempotency_field else {
+            return;
+        };
+
+        let key = self
+            .options
+            .idempotency_key
+            .get_or_insert_with(|| Uuid::new_v4().to_string());
+
+        if let RequestBody::Json(serde_json::Value::Object(body)) = &mut self.body {
+            body.insert(field.clone(), key.clone().into());
+        }
+    }
+
+    pub(crate) fn build(
+        &self,
+        client: &reqwest::Client,
+        default_headers: &HeaderMap,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        if self.invalid_body {
+            return Err(Error::BadJson);
+        }
+
+        let mut headers = default_headers.clone();
+        if !self.headers.contains_key(header::ACCEPT) {
+            let accept = match self.response_kind {
+                ResponseKind::Json => "application/json",
+                _ => "*/*",
+            };
+
+            headers.insert(header::ACCEPT, HeaderValue::from_static(accept));
+        }
+
+        for name in self.headers.keys() {
+            headers.remove(name);
+        }
+
+        for (name, value) in &self.headers {
+            headers.append(name, value.clone());
+        }
+
+        let mut builder = client
+            .request(self.method.clone(), self.url())
+            .headers(headers);
+
+        if !self.query.is_empty() {
+            builder = builder.query(&self.query);
+        }
+
+        builder = match &self.body {
+            RequestBody::Empty => builder,
+            RequestBody::Json(json) => builder.json(json),
+            RequestBody::Form(pairs) => builder.form(pairs),
+            RequestBody::Raw(bytes) => builder.body(bytes.clone()),
+            RequestBody::Multipart(fields) => {
+                let mut form = Form::new();
+                for field in fields {
+                    form = match field {
+                        MultipartField::Text { name, value } => {
+                            form.text(name.clone(), value.clone())
+                        }
+                        MultipartField::File {
+                            name,
+                            file_name,
+                            mime,
+                            bytes,
+                        } => form.part(
+                            name.clone(),
+                            Part::bytes(bytes.clone())
+                                .file_name(file_name.clone())
+                                .mime_str(mime)?,
+                        ),
+                    };
+                }
+
+                builder.multipart(form)
+            }
+        };
+
+        Ok(builder)
+    }
+}
+
+impl ApiResponse {
+    pub(crate) fn new(response: Response) -> Self {
+        Self(response)
+    }
+
+    pub fn status(&self) -> u16 {
+        self.0.status().as_u16()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.0.headers()
+    }
+
+    pub async fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        Ok(self.0.json::<T>().await?)
+    }
+
+    pub async fn bytes(self) -> Result<Vec<u8>, Error> {
+        Ok(self.0.bytes().await?.to_vec())
+    }
+
+    pub async fn text(self) -> Result<String, Error> {
+        Ok(self.0.text().await?)
+    }
+}
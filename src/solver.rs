@@ -0,0 +1,258 @@
+use std::{future::Future, pin::Pin, sync::Mutex};
+
+use crate::{
+    ApiError, Error,
+    api::{challenge, two_step_verification},
+    challenge::{CaptchaChallenge, Challenge, ChallengeType, ChefChallenge, RequiredChallenge},
+    client::Client,
+};
+
+pub type SolverFuture<'a> = Pin<Box<dyn Future<Output = Result<String, Error>> + Send + 'a>>;
+pub type ChefSolverFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<serde_json::Value, Error>> + Send + 'a>>;
+
+/// Solves challenges for the client, once set with `Client::set_challenge_solver`
+/// challenged requests are continued and sent again without the caller noticing
+pub trait ChallengeSolver: Send + Sync {
+    fn can_solve(&self, challenge: &Challenge) -> bool {
+        challenge.kind == ChallengeType::TwoStepVerification
+    }
+
+    /// Returns the verification token for the challenge
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a>;
+
+    /// Returns the captcha token for the challenge, by default captchas are left to the caller
+    fn solve_captcha<'a>(
+        &'a self,
+        _client: &'a mut Client,
+        challenge: &'a CaptchaChallenge,
+    ) -> SolverFuture<'a> {
+        Box::pin(async move {
+            Err(Error::ApiError(ApiError::ChallengeRequired(
+                RequiredChallenge::Captcha(challenge.clone()),
+            )))
+        })
+    }
+
+    /// Returns what the challenge's scripts produced, by default chef challenges are left to the caller
+    fn solve_chef<'a>(
+        &'a self,
+        _client: &'a mut Client,
+        challenge: &'a ChefChallenge,
+    ) -> ChefSolverFuture<'a> {
+        Box::pin(async move {
+            Err(Error::ApiError(ApiError::ChallengeRequired(
+                RequiredChallenge::Chef(challenge.clone()),
+            )))
+        })
+    }
+}
+
+impl std::fmt::Debug for dyn ChallengeSolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChallengeSolver")
+    }
+}
+
+/// Asks for a code from an authenticator app
+pub struct AuthenticatorSolver<F>(F);
+
+/// Sends a code to the user's email, then asks for it
+pub struct EmailSolver<F>(F);
+
+/// Sends a code to the user's phone, then asks for it
+pub struct SmsSolver<F>(F);
+
+/// Uses up the given recovery codes, one for each challenge
+pub struct RecoveryCodeSolver(Mutex<Vec<String>>);
+
+impl<F, Fut> AuthenticatorSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    pub fn new(code: F) -> Self {
+        Self(code)
+    }
+}
+
+impl<F, Fut> EmailSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    pub fn new(code: F) -> Self {
+        Self(code)
+    }
+}
+
+impl<F, Fut> SmsSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    pub fn new(code: F) -> Self {
+        Self(code)
+    }
+}
+
+impl RecoveryCodeSolver {
+    pub fn new(codes: Vec<String>) -> Self {
+        Self(Mutex::new(codes))
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+}
+
+impl<F, Fut> ChallengeSolver for AuthenticatorSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            let code = (self.0)(challenge.clone()).await;
+            two_step_verification::v1::authenticator_verify(
+                client,
+                challenge.metadata.user_id()?,
+                &code,
+                challenge.metadata.action_type,
+                &challenge.metadata.server_challenge_id,
+            )
+            .await
+        })
+    }
+}
+
+impl<F, Fut> ChallengeSolver for EmailSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            let user_id = challenge.metadata.user_id()?;
+            let action_type = challenge.metadata.action_type;
+            let challenge_id = &challenge.metadata.server_challenge_id;
+
+            two_step_verification::v1::email_send_code(client, user_id, action_type, challenge_id)
+                .await?;
+
+            let code = (self.0)(challenge.clone()).await;
+            two_step_verification::v1::email_verify(
+                client,
+                user_id,
+                &code,
+                action_type,
+                challenge_id,
+            )
+            .await
+        })
+    }
+}
+
+impl<F, Fut> ChallengeSolver for SmsSolver<F>
+where
+    F: Fn(Challenge) -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            let user_id = challenge.metadata.user_id()?;
+            let action_type = challenge.metadata.action_type;
+            let challenge_id = &challenge.metadata.server_challenge_id;
+
+            two_step_verification::v1::sms_send_code(client, user_id, action_type, challenge_id)
+                .await?;
+
+            let code = (self.0)(challenge.clone()).await;
+            two_step_verification::v1::sms_verify(client, user_id, &code, action_type, challenge_id)
+                .await
+        })
+    }
+}
+
+impl ChallengeSolver for RecoveryCodeSolver {
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            let code = self.0.lock().unwrap().pop();
+            let Some(code) = code else {
+                return Err(Error::ApiError(ApiError::ChallengeRequired(
+                    RequiredChallenge::Generic(challenge.clone()),
+                )));
+            };
+
+            two_step_verification::v1::recovery_code_verify(
+                client,
+                challenge.metadata.user_id()?,
+                &code,
+                challenge.metadata.action_type,
+                &challenge.metadata.server_challenge_id,
+            )
+            .await
+        })
+    }
+}
+
+impl Client {
+    pub fn set_challenge_solver(&mut self, solver: impl ChallengeSolver + 'static) {
+        self.requestor.solver = Some(Box::new(solver));
+    }
+
+    pub fn remove_challenge_solver(&mut self) {
+        self.requestor.solver = None;
+    }
+
+    pub(crate) async fn solve_challenge(
+        &mut self,
+        solver: &dyn ChallengeSolver,
+        challenge: &RequiredChallenge,
+    ) -> Result<(), Error> {
+        let challenge = match challenge {
+            RequiredChallenge::Generic(challenge) => challenge,
+            RequiredChallenge::Captcha(challenge) => {
+                let captcha_token = solver.solve_captcha(self, challenge).await?;
+                self.requestor
+                    .send(challenge::v1::continue_captcha_request(
+                        challenge,
+                        &captcha_token,
+                    ))
+                    .await?;
+
+                return self
+                    .requestor
+                    .queue_captcha_challenge(challenge, &captcha_token);
+            }
+            RequiredChallenge::Chef(challenge) => {
+                let metadata = solver.solve_chef(self, challenge).await?;
+                self.requestor
+                    .send(challenge::v1::continue_chef_request(challenge, &metadata))
+                    .await?;
+
+                return self.requestor.queue_chef_challenge(challenge, &metadata);
+            }
+        };
+
+        if !solver.can_solve(challenge) {
+            return Err(Error::ApiError(ApiError::ChallengeRequired(
+                RequiredChallenge::Generic(challenge.clone()),
+            )));
+        }
+
+        let verification_token = solver.solve(self, challenge).await?;
+
+        // sent directly, the challenge can't be challenged again
+        self.requestor
+            .send(challenge::v1::continue_request(
+                challenge,
+                &verification_token,
+            ))
+            .await?;
+
+        self.requestor
+            .queue_challenge(challenge, &verification_token)
+            .await
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::SystemTime;
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+use crate::{
+    api::two_step_verification,
+    challenge::Challenge,
+    client::Client,
+    solver::{ChallengeSolver, SolverFuture},
+};
+
+/// Generates authenticator codes (RFC 6238), it's also a challenge solver on its own
+///
+/// ```
+/// use roblox_api::totp::Totp;
+///
+/// let totp = Totp::from_base32("JBSWY3DPEHPK3PXP").unwrap();
+/// assert_eq!(totp.code().len(), 6);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Totp {
+    secret: Vec<u8>,
+    digits: u32,
+    period: u64,
+    skew: i64,
+}
+
+// the secret stays out of logs
+impl std::fmt::Debug for Totp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Totp")
+            .field("secret", &"<redacted>")
+            .field("digits", &self.digits)
+            .field("period", &self.period)
+            .field("skew", &self.skew)
+            .finish()
+    }
+}
+
+impl Totp {
+    pub fn new(secret: Vec<u8>) -> Self {
+        Self {
+            secret,
+            digits: 6,
+            period: 30,
+            skew: 0,
+        }
+    }
+
+    /// The secret as shown by roblox when setting up the authenticator, spaces and case are ignored
+    pub fn from_base32(secret: &str) -> Option<Self> {
+        let secret = secret.replace(' ', "").to_uppercase();
+        let secret = base32::decode(
+            base32::Alphabet::Rfc4648 { padding: false },
+            secret.trim_end_matches('='),
+        )?;
+
+        Some(Self::new(secret))
+    }
+
+    /// `None` unless it's between 1 and 10, the code comes from a 31 bit number
+    pub fn digits(mut self, digits: u32) -> Option<Self> {
+        if !(1..=10).contains(&digits) {
+            return None;
+        }
+
+        self.digits = digits;
+        Some(self)
+    }
+
+    /// How long a code lasts for in seconds, `None` if it's 0
+    pub fn period(mut self, period: u64) -> Option<Self> {
+        if period == 0 {
+            return None;
+        }
+
+        self.period = period;
+        Some(self)
+    }
+
+    /// How many periods to move the clock by, for when the local clock is off from roblox's
+    pub fn skew(mut self, skew: i64) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// The code for the given unix timestamp, the skew isn't applied
+    pub fn code_at(&self, unix: u64) -> String {
+        self.code_for_step(unix / self.period)
+    }
+
+    pub fn code(&self) -> String {
+        let unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let step = (unix / self.period).saturating_add_signed(self.skew);
+        self.code_for_step(step)
+    }
+
+    fn code_for_step(&self, step: u64) -> String {
+        let mut mac = Hmac::<Sha1>::new_from_slice(&self.secret).unwrap();
+        mac.update(&step.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+
+        let code = binary as u64 % 10u64.pow(self.digits);
+        format!("{:0width$}", code, width = self.digits as usize)
+    }
+}
+
+impl ChallengeSolver for Totp {
+    fn solve<'a>(&'a self, client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            two_step_verification::v1::authenticator_verify(
+                client,
+                challenge.metadata.user_id()?,
+                &self.code(),
+                challenge.metadata.action_type,
+                &challenge.metadata.server_challenge_id,
+            )
+            .await
+        })
+    }
+}
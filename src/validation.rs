@@ -6,13 +6,15 @@ use crate::{
     ApiError, Currency, Error,
     api::auth,
     challenge::{
-        CHALLENGE_ID_HEADER, CHALLENGE_METADATA_HEADER, CHALLENGE_TYPE_HEADER, Challenge,
-        ChallengeMetadata, ChallengeType, ChefChallengeMetadata,
+        CHALLENGE_ID_HEADER, CHALLENGE_METADATA_HEADER, CHALLENGE_TYPE_HEADER, CaptchaChallenge,
+        CaptchaChallengeMetadata, Challenge, ChallengeMetadata, ChallengeType, ChefChallenge,
+        ChefChallengeMetadata, RequiredChallenge,
     },
     client::ClientRequestor,
     ratelimit::{
         RATELIMIT_LIMIT_HEADER, RATELIMIT_REMAINING_HEADER, RATELIMIT_RESET_HEADER, Ratelimit,
     },
+    request::ApiRequest,
 };
 
 const TOKEN_HEADER: &str = "x-csrf-token";
@@ -57,48 +59,38 @@ fn ratelimit_from_headers(
     }
 }
 
+// anything malformed gives `None`, a bad 403 shouldn't take the whole program down
 fn challenge_from_headers(
     id: Option<HeaderValue>,
     kind: Option<HeaderValue>,
     metadata_b64: Option<HeaderValue>,
-) -> Option<Challenge> {
-    if let (Some(id), Some(kind), Some(metadata_b64)) = (id, kind, metadata_b64) {
-        let kind = ChallengeType::from(kind.to_str().unwrap());
-        match kind {
-            ChallengeType::Chef => {
-                let _metadata: ChefChallengeMetadata = serde_json::from_slice(
-                    BASE64_STANDARD
-                        .decode(metadata_b64.to_str().unwrap())
-                        .unwrap()
-                        .as_slice(),
-                )
-                .unwrap();
-
-                todo!("Unsupported challenge-type: \"chef\"");
-            }
+) -> Option<RequiredChallenge> {
+    let id = id?.to_str().ok()?.to_string();
+    let metadata = BASE64_STANDARD.decode(metadata_b64?.to_str().ok()?).ok()?;
 
-            ChallengeType::Captcha => {
-                todo!("Unsupported challenge-type: \"captcha\"")
-            }
+    let kind = ChallengeType::from(kind?.to_str().ok()?);
+    match kind {
+        ChallengeType::Chef => {
+            let metadata: ChefChallengeMetadata =
+                serde_json::from_slice(metadata.as_slice()).ok()?;
 
-            _ => {
-                let metadata: ChallengeMetadata = serde_json::from_slice(
-                    BASE64_STANDARD
-                        .decode(metadata_b64.to_str().unwrap())
-                        .unwrap()
-                        .as_slice(),
-                )
-                .unwrap();
-
-                Some(Challenge {
-                    id: id.to_str().unwrap().to_string(),
-                    kind,
-                    metadata,
-                })
-            }
+            Some(RequiredChallenge::Chef(ChefChallenge { id, metadata }))
+        }
+
+        ChallengeType::Captcha => {
+            let metadata: CaptchaChallengeMetadata =
+                serde_json::from_slice(metadata.as_slice()).ok()?;
+
+            Some(RequiredChallenge::Captcha(CaptchaChallenge {
+                id,
+                metadata,
+            }))
+        }
+
+        _ => {
+            let metadata: ChallengeMetadata = serde_json::from_slice(metadata.as_slice()).ok()?;
+            Some(RequiredChallenge::Generic(Challenge { id, kind, metadata }))
         }
-    } else {
-        None
     }
 }
 
@@ -111,14 +103,8 @@ impl ClientRequestor {
     // NOTE: this doesn't work on all apis, since some apis expect a custom token,
     // you'll know which ones are affected based on the `TokenValidation` error
     pub(crate) async fn ensure_token(&mut self) -> Result<(), Error> {
-        let result = self
-            .client
-            .post(format!("{}//", auth::URL))
-            .headers(self.default_headers.clone())
-            .send()
-            .await;
-
-        let result = self.validate_response(result).await;
+        // goes around dry-run, nothing gets changed by it
+        let result = self.execute(ApiRequest::post(auth::URL, "/")).await;
 
         if let Err(Error::ApiError(ApiError::TokenValidation)) = result {
             return Ok(());
@@ -289,7 +275,11 @@ impl ClientRequestor {
                                         challenge_type.clone(),
                                         challenge_metadata_b64.clone(),
                                     );
-                                    ApiError::ChallengeRequired(challenge.unwrap())
+
+                                    match challenge {
+                                        Some(challenge) => ApiError::ChallengeRequired(challenge),
+                                        None => ApiError::Unknown(code, Some(x.message.to_owned())),
+                                    }
                                 }
 
                                 "Challenge failed to authorize request" => {
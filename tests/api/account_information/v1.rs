@@ -8,3 +8,9 @@ async fn roblox_badges() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn phone() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    account_information::v1::phone(&mut client).await.unwrap();
+}
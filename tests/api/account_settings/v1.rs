@@ -0,0 +1,8 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{api::account_settings, client::Client};
+
+#[tokio::test]
+async fn email() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    account_settings::v1::email(&mut client).await.unwrap();
+}
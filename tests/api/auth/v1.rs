@@ -1 +1,8 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{api::auth, client::Client};
 
+#[tokio::test]
+async fn pin_status() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    auth::v1::pin_status(&mut client).await.unwrap();
+}
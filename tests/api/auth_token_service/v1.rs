@@ -1,5 +1,5 @@
 use dotenvy_macro::dotenv;
-use roblox_api::{api::auth_token_service, client::Client};
+use roblox_api::{Error, api::auth_token_service, client::Client, options::CancellationToken};
 
 #[tokio::test]
 async fn login_create() {
@@ -79,5 +79,40 @@ async fn qr_code_image() {
         .await
         .unwrap();
 
-    assert!(bytes.len() > 0);
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn quick_login() {
+    let mut client = Client::default();
+    let quick_login = auth_token_service::v1::QuickLogin::start(&mut client)
+        .await
+        .unwrap();
+
+    assert!(!quick_login.image().is_empty());
+    assert!(!quick_login.is_expired());
+
+    quick_login.cancel(&mut client).await.unwrap();
+}
+
+#[tokio::test]
+async fn quick_login_cancellation() {
+    let mut client = Client::default();
+
+    let token = CancellationToken::new();
+    let mut quick_login = auth_token_service::v1::QuickLogin::start(&mut client)
+        .await
+        .unwrap()
+        .cancellation(token.clone());
+
+    token.cancel();
+    let result = quick_login.next_status(&mut client).await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+#[test]
+fn unknown_login_status() {
+    let status: auth_token_service::v1::LoginStatus =
+        serde_json::from_str(r#""SomethingNew""#).unwrap();
+    assert_eq!(status, auth_token_service::v1::LoginStatus::Unknown);
 }
@@ -0,0 +1,2 @@
+mod v1;
+mod v2;
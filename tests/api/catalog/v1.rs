@@ -0,0 +1,56 @@
+use roblox_api::{
+    AssetTypeId, Paging,
+    api::catalog::{
+        self,
+        v1::{CatalogItem, ItemType},
+    },
+    client::Client,
+};
+
+#[test]
+fn asset_type() {
+    let item: CatalogItem = serde_json::from_str(
+        r#"{"id":1028606,"itemType":"Asset","assetType":8,"name":"Red Baseball Cap","creatorName":"Roblox","creatorType":"User","creatorTargetId":1}"#,
+    )
+    .unwrap();
+    assert_eq!(item.asset_type, Some(AssetTypeId::Hat));
+
+    // sent back the same way
+    let json = serde_json::to_value(&item).unwrap();
+    assert_eq!(json["assetType"], 8);
+}
+
+#[tokio::test]
+async fn item_details() {
+    let mut client = Client::default();
+    client.ensure_token().await.unwrap();
+
+    catalog::v1::item_details(
+        &mut client,
+        &[(ItemType::Asset, 1028606), (ItemType::Bundle, 192)],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn bundle_details() {
+    let mut client = Client::default();
+    catalog::v1::bundle_details(&mut client, 192).await.unwrap();
+}
+
+#[tokio::test]
+async fn bundles_details() {
+    let mut client = Client::default();
+    catalog::v1::bundles_details(&mut client, &[192, 238])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn asset_bundles() {
+    let mut client = Client::default();
+    catalog::v1::asset_bundles(&mut client, 2510230574, Paging::default())
+        .await
+        .unwrap();
+}
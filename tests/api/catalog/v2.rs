@@ -0,0 +1,31 @@
+use roblox_api::{
+    AssetTypeId, Paging,
+    api::catalog::{
+        self,
+        v2::{Category, SearchFilter, SortType},
+    },
+    client::Client,
+};
+
+#[tokio::test]
+async fn search() {
+    let mut client = Client::default();
+    let filter = SearchFilter {
+        keyword: Some("hat"),
+        category: Some(Category::Accessories),
+        sort: Some(SortType::MostFavorited),
+        ..Default::default()
+    };
+
+    catalog::v2::search(&mut client, &filter, Paging::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn recommendations() {
+    let mut client = Client::default();
+    catalog::v2::recommendations(&mut client, 1028606, AssetTypeId::Hat, 10)
+        .await
+        .unwrap();
+}
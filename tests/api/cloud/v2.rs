@@ -0,0 +1,19 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{api::cloud, client::Client};
+
+const USER_ID: u64 = 3139503587;
+const BHOP_GROUP_ID: u64 = 6980477;
+
+#[tokio::test]
+async fn user() {
+    let mut client = Client::from_api_key(dotenv!("ROBLOX_API_KEY").into());
+    let user = cloud::v2::user(&mut client, USER_ID).await.unwrap();
+    assert_eq!(user.id, USER_ID.to_string());
+}
+
+#[tokio::test]
+async fn group() {
+    let mut client = Client::from_api_key(dotenv!("ROBLOX_API_KEY").into());
+    let group = cloud::v2::group(&mut client, BHOP_GROUP_ID).await.unwrap();
+    assert_eq!(group.id, BHOP_GROUP_ID.to_string());
+}
@@ -1,6 +1,7 @@
 use dotenvy_macro::dotenv;
 
 use roblox_api::{
+    Paging,
     api::{economy, users},
     client::Client,
 };
@@ -31,3 +32,19 @@ async fn currency_from_group_id() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn resellers() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    economy::v1::resellers(&mut client, 1028606, Paging::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn resale_data() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    economy::v1::resale_data(&mut client, 1028606)
+        .await
+        .unwrap();
+}
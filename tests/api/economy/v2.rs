@@ -1,7 +1,30 @@
-use roblox_api::{api::economy, client::Client};
+use dotenvy_macro::dotenv;
+use roblox_api::{
+    Paging,
+    api::{
+        economy::{self, v2::TransactionType},
+        users,
+    },
+    client::Client,
+};
 
 #[tokio::test]
 async fn details() {
     let mut client = Client::default();
     economy::v2::details(&mut client, 48474313).await.unwrap();
 }
+
+#[tokio::test]
+async fn user_transactions() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+
+    let authenticated = users::v1::authenticated_details(&mut client).await.unwrap();
+    economy::v2::user_transactions(
+        &mut client,
+        authenticated.id,
+        TransactionType::Purchase,
+        Paging::default(),
+    )
+    .await
+    .unwrap();
+}
@@ -1,5 +1,12 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+
 use dotenvy_macro::dotenv;
-use roblox_api::{Paging, api::friends, client::Client};
+use roblox_api::{Paging, SortOrder, api::friends, client::Client};
+
+use crate::common::{self, Response};
 
 const USER_ID: u64 = 3139503587;
 
@@ -110,3 +117,77 @@ async fn user_friend_statuses() {
 
     assert_eq!(statuses.first().unwrap().id, 1);
 }
+
+#[tokio::test]
+async fn user_friends_all() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    friends::v1::user_friends_all(&mut client, USER_ID)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn user_friends_paging() {
+    let pages = Arc::new(AtomicU32::new(0));
+    let url = {
+        let pages = pages.clone();
+        common::serve(move |request| {
+            let page = pages.fetch_add(1, Ordering::SeqCst);
+            match page {
+                0 => {
+                    assert_eq!(
+                        request.path,
+                        "/users/1/friends/find?limit=25&userSort=1"
+                    );
+                }
+                1 => assert!(!request.path.contains("cursor")),
+                2 => assert!(request.path.contains("cursor=same")),
+                _ => panic!("the repeated cursor should have stopped it"),
+            }
+
+            // never runs out, always giving the same cursor
+            Response::json(
+                r#"{"PageItems":[{"id":2}],"NextCursor":"same","PreviousCursor":null,"HasMore":true}"#,
+            )
+        })
+        .await
+    };
+
+    let mut client = Client::from_cookie("".into());
+    client.set_service_url(friends::v1::URL, &url);
+
+    let page = friends::v1::user_friends_find(
+        &mut client,
+        1,
+        Paging::new(None, Some(25), Some(SortOrder::Descending)),
+    )
+    .await
+    .unwrap();
+    assert_eq!(page.next_cursor.as_deref(), Some("same"));
+
+    let users = friends::v1::user_friends_all(&mut client, 1).await.unwrap();
+    assert_eq!(users.len(), 2);
+}
+
+#[tokio::test]
+async fn friend_request() {
+    let mut client = common::dry_run_client();
+
+    let plan = common::recorded(
+        friends::v1::send_friend_request(
+            &mut client,
+            1,
+            friends::v1::FriendRequestSourceType::UserProfile,
+        )
+        .await,
+    );
+
+    assert_eq!(
+        plan.url,
+        "https://friends.roblox.com/v1/users/1/request-friendship"
+    );
+    assert_eq!(
+        plan.body,
+        Some(serde_json::json!({ "friendshipOriginSourceType": "UserProfile" }))
+    );
+}
@@ -1,10 +1,18 @@
 use dotenvy_macro::dotenv;
 use roblox_api::{
     ApiError, Error, Paging,
-    api::{groups, users},
+    api::{
+        groups::{
+            self,
+            v1::{AuditActionType, AuditDescription, RelationshipType},
+        },
+        users,
+    },
     client::Client,
 };
 
+use crate::common;
+
 const USER_ID: u64 = 3139503587;
 
 const BHOP_GROUP_ID: u64 = 6980477;
@@ -115,3 +123,182 @@ async fn join_request() {
 
     groups::v1::join(&mut client, BHOP_GROUP_ID).await.unwrap();
 }
+
+#[test]
+fn audit_log_details() {
+    let entry: groups::v1::AuditLogEntry = serde_json::from_value(serde_json::json!({
+        "actor": {
+            "user": {
+                "userId": 1,
+                "username": "Roblox",
+                "displayName": "Roblox",
+                "hasVerifiedBadge": true,
+            },
+            "role": { "id": 2, "name": "Owner", "rank": 255 },
+        },
+        "actionType": "Change Rank",
+        "description": {
+            "TargetId": 3,
+            "TargetName": "builderman",
+            "OldRoleSetId": 4,
+            "OldRoleSetName": "Member",
+            "NewRoleSetId": 5,
+            "NewRoleSetName": "Admin",
+        },
+        "created": "2024-01-01T00:00:00Z",
+    }))
+    .unwrap();
+
+    assert_eq!(entry.action_type, AuditActionType::ChangeRank);
+    assert_eq!(entry.action_type.to_string(), "ChangeRank");
+    assert_eq!(
+        entry.details(),
+        AuditDescription::ChangeRank {
+            target_id: 3,
+            target_name: "builderman".into(),
+            old_role_id: 4,
+            old_role_name: "Member".into(),
+            new_role_id: 5,
+            new_role_name: "Admin".into(),
+        }
+    );
+
+    let action_type: AuditActionType = serde_json::from_str("\"Something New\"").unwrap();
+    assert_eq!(action_type, AuditActionType::Unknown);
+}
+
+#[tokio::test]
+async fn search() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::search(&mut client, "roblox", true, Paging::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn lookup() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::lookup(&mut client, "Roblox").await.unwrap();
+}
+
+#[tokio::test]
+async fn relationships() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::relationships(
+        &mut client,
+        ROBLOX_GROUP_ID,
+        RelationshipType::Allies,
+        0,
+        10,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn policies() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::policies(&mut client, &[ROBLOX_GROUP_ID, BHOP_GROUP_ID])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn metadata() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::metadata(&mut client).await.unwrap();
+}
+
+#[tokio::test]
+async fn primary_group() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::primary_group(&mut client, USER_ID)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn authenticated_user_roles() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    groups::v1::authenticated_user_roles(&mut client)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn role_permissions_update() {
+    let mut client = common::dry_run_client();
+
+    let permissions: groups::v1::Permissions = serde_json::from_value(serde_json::json!({
+        "groupPostsPermissions": {
+            "viewWall": true,
+            "postToWall": false,
+            "deleteFromWall": false,
+            "viewStatus": true,
+            "postToStatus": false,
+        },
+        "groupForumsPermissions": {
+            "pinPosts": false,
+            "lockPosts": false,
+            "createPosts": false,
+            "removePosts": false,
+            "createComments": false,
+            "removeComments": false,
+            "manageCategories": false,
+        },
+        "groupContentModerationPermissions": {
+            "manageKeywordBlockList": false,
+            "viewKeywordBlockList": false,
+        },
+        "groupMembershipPermissions": {
+            "changeRank": true,
+            "banMembers": false,
+            "inviteMembers": false,
+            "removeMembers": false,
+        },
+        "groupManagementPermissions": {
+            "manageClan": false,
+            "manageRelationships": false,
+            "viewAuditLogs": false,
+        },
+        "groupEconomyPermissions": {
+            "createItems": false,
+            "manageItems": false,
+            "advertiseGroup": false,
+            "addGroupPlaces": false,
+            "spendGroupFunds": false,
+            "manageGroupGames": false,
+            "viewGroupPayouts": false,
+            "viewAnalytics": false,
+        },
+        "groupOpenCloudPermissions": {
+            "useCloudAuthentication": false,
+            "administerCloudAuthentication": false,
+        },
+    }))
+    .unwrap();
+
+    let plan = common::recorded(
+        groups::v1::update_role_permissions(&mut client, 1, 2, &permissions).await,
+    );
+
+    let body = plan.body.unwrap();
+    assert_eq!(plan.method, "PATCH");
+    assert_eq!(body["permissions"]["ViewWall"], true);
+    assert_eq!(body["permissions"]["ChangeRank"], true);
+    assert_eq!(body["permissions"]["ManageClan"], false);
+    assert!(body["permissions"].get("groupPostsPermissions").is_none());
+}
+
+#[tokio::test]
+async fn delete_user_wall_posts() {
+    let mut client = common::dry_run_client();
+    let plan = common::recorded(groups::v1::delete_user_wall_posts(&mut client, 1, 2).await);
+
+    assert_eq!(plan.method, "DELETE");
+    assert_eq!(
+        plan.url,
+        "https://groups.roblox.com/v1/groups/1/wall/users/2/posts"
+    );
+    assert_eq!(plan.body, Some(serde_json::json!({})));
+}
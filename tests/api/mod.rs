@@ -1,9 +1,12 @@
 mod account_information;
+mod account_settings;
 mod assets;
 mod auth;
 mod auth_token_service;
 mod avatar;
 mod badges;
+mod catalog;
+mod cloud;
 mod economy;
 mod friends;
 mod gamepasses;
@@ -16,5 +19,8 @@ mod premium_features;
 mod presence;
 mod private_messages;
 mod thumbnails;
+mod token_metadata_service;
+mod trades;
+mod two_step_verification;
 mod user_blocking;
 mod users;
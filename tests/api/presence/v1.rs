@@ -8,3 +8,11 @@ async fn presence() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn last_online() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    presence::v1::last_online(&mut client, &[1, 2, 3])
+        .await
+        .unwrap();
+}
@@ -1,10 +1,15 @@
 use dotenvy_macro::dotenv;
 use roblox_api::{
     Paging,
-    api::private_messages::{self, v1::MessageTab},
+    api::{
+        private_messages::{self, v1::MessageTab},
+        users,
+    },
     client::Client,
 };
 
+use crate::common::{self, Response};
+
 #[tokio::test]
 async fn unread_count() {
     let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
@@ -32,3 +37,84 @@ async fn announcements() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn can_message() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    private_messages::v1::can_message(&mut client, 1)
+        .await
+        .unwrap();
+}
+
+fn message(id: u64, sender: u64, recipient: u64, subject: &str) -> private_messages::v1::Message {
+    let user =
+        |id: u64| format!(r#"{{"id":{id},"name":"a","displayName":"a","hasVerifiedBadge":false}}"#);
+
+    serde_json::from_str(&format!(
+        r#"{{"id":{id},"subject":"{subject}","body":"","sender":{},"recipient":{},"created":"2024-01-01T00:00:00Z","updated":"2024-01-01T00:00:00Z","isRead":false,"isSystemMessage":false,"isReportAbuseDisplayed":false}}"#,
+        user(sender),
+        user(recipient)
+    ))
+    .unwrap()
+}
+
+#[test]
+fn thread_matching() {
+    let first = message(1, 1, 2, "Trade");
+    assert_eq!(
+        message(2, 2, 1, "RE: Re:  RE: Trade").base_subject(),
+        "Trade"
+    );
+
+    // either way around, with any number of replies
+    assert!(first.is_same_thread(&message(2, 2, 1, "RE: Trade")));
+    assert!(first.is_same_thread(&message(3, 1, 2, "RE: RE: Trade")));
+
+    assert!(!first.is_same_thread(&message(4, 1, 3, "Trade")));
+    assert!(!first.is_same_thread(&message(5, 1, 2, "Trades")));
+}
+
+#[tokio::test]
+async fn send_body() {
+    let url =
+        common::serve(|_| Response::json(r#"{"id":1,"name":"Roblox","displayName":"Roblox"}"#))
+            .await;
+
+    let mut client = common::dry_run_client();
+    client.set_service_url(users::v1::URL, &url);
+
+    let plan = common::recorded(private_messages::v1::send(&mut client, 2, "Trade", "Hello").await);
+
+    assert_eq!(
+        plan.url,
+        "https://privatemessages.roblox.com/v1/messages/send"
+    );
+    assert_eq!(
+        plan.body,
+        Some(serde_json::json!({
+            "userId": 1,
+            "recipientId": 2,
+            "subject": "Trade",
+            "body": "Hello",
+            "replyMessageId": null,
+            "includePreviousMessage": false,
+        }))
+    );
+
+    let plan = common::recorded(
+        private_messages::v1::reply(&mut client, &message(7, 2, 1, "RE: Trade"), "Sure", true)
+            .await,
+    );
+
+    assert_eq!(
+        plan.body,
+        Some(serde_json::json!({
+            "userId": 1,
+            "recipientId": 2,
+            "subject": "RE: Trade",
+            "body": "Sure",
+            "replyMessageId": 7,
+            "includePreviousMessage": true,
+        }))
+    );
+}
@@ -40,7 +40,7 @@ async fn assets() {
     .await
     .unwrap();
 
-    thumbnails.get(0).unwrap();
+    thumbnails.first().unwrap();
 }
 
 #[tokio::test]
@@ -57,7 +57,7 @@ async fn badge_icons() {
     .await
     .unwrap();
 
-    thumbnails.get(0).unwrap();
+    thumbnails.first().unwrap();
 }
 
 #[tokio::test]
@@ -74,7 +74,7 @@ async fn bundles() {
     .await
     .unwrap();
 
-    thumbnails.get(0).unwrap();
+    thumbnails.first().unwrap();
 }
 
 #[tokio::test]
@@ -97,5 +97,5 @@ async fn batch() {
     .await
     .unwrap();
 
-    thumbnails.get(0).unwrap();
+    thumbnails.first().unwrap();
 }
@@ -0,0 +1 @@
+mod v1;
@@ -0,0 +1,10 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{Paging, api::token_metadata_service, client::Client};
+
+#[tokio::test]
+async fn sessions() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    token_metadata_service::v1::sessions(&mut client, Paging::default())
+        .await
+        .unwrap();
+}
@@ -0,0 +1,61 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{
+    Paging,
+    api::trades::{self, v1::TradeStatusType},
+    client::Client,
+};
+
+use crate::common;
+
+#[tokio::test]
+async fn trades() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    trades::v1::trades(&mut client, TradeStatusType::Inbound, Paging::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn trade_count() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    trades::v1::trade_count(&mut client, TradeStatusType::Inbound)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn can_trade_with() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    trades::v1::can_trade_with(&mut client, 1).await.unwrap();
+}
+
+#[tokio::test]
+async fn trade_offers() {
+    let mut client = common::dry_run_client();
+
+    let offers = [
+        trades::v1::TradeOffer {
+            user_id: 1,
+            user_asset_ids: vec![10],
+            robux: 0,
+        },
+        trades::v1::TradeOffer {
+            user_id: 2,
+            user_asset_ids: vec![20, 30],
+            robux: 5,
+        },
+    ];
+
+    let plan = common::recorded(trades::v1::send(&mut client, &offers).await);
+
+    assert_eq!(plan.url, "https://trades.roblox.com/v1/trades/send");
+    assert_eq!(
+        plan.body,
+        Some(serde_json::json!({
+            "offers": [
+                { "userId": 1, "userAssetIds": [10], "robux": 0 },
+                { "userId": 2, "userAssetIds": [20, 30], "robux": 5 },
+            ]
+        }))
+    );
+}
@@ -0,0 +1,48 @@
+use dotenvy_macro::dotenv;
+use roblox_api::{api::two_step_verification, challenge::ActionType, client::Client};
+
+use crate::common::{self, Response};
+
+const USER_ID: u64 = 3139503587;
+
+#[tokio::test]
+async fn metadata() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    two_step_verification::v1::metadata(&mut client, USER_ID, None, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn metadata_query() {
+    let url = common::serve(|request| {
+        match request.path.as_str() {
+            "/metadata?userId=1" | "/metadata?userId=1&challengeId=ticket&actionType=Login" => {}
+            path => panic!("unexpected path {path}"),
+        }
+
+        Response::json(
+            r#"{"twoStepVerificationEnabled":true,"authenticatorQrCodeSize":null,"emailCodeLength":6,"authenticatorCodeLength":6}"#,
+        )
+    })
+    .await;
+
+    let mut client = Client::from_cookie("".into());
+    client.set_service_url(two_step_verification::v1::URL, &url);
+
+    // nothing is sent for what wasn't given
+    two_step_verification::v1::metadata(&mut client, 1, None, None)
+        .await
+        .unwrap();
+    two_step_verification::v1::metadata(&mut client, 1, Some("ticket"), Some(ActionType::Login))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn configuration() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    two_step_verification::v1::configuration(&mut client, USER_ID, None, None)
+        .await
+        .unwrap();
+}
@@ -1,8 +1,16 @@
 use dotenvy_macro::dotenv;
-use roblox_api::{api::user_blocking, client::Client};
+use roblox_api::{Paging, api::user_blocking, client::Client};
 
 #[tokio::test]
 async fn is_blocked() {
     let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
     user_blocking::v1::is_blocked(&mut client, 1).await.unwrap();
 }
+
+#[tokio::test]
+async fn blocked_users() {
+    let mut client = Client::from_cookie(dotenv!("ROBLOX_COOKIE").into());
+    user_blocking::v1::blocked_users(&mut client, Paging::default())
+        .await
+        .unwrap();
+}
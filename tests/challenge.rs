@@ -0,0 +1,369 @@
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU32, Ordering},
+};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use roblox_api::{
+    ApiError, Error,
+    api::challenge,
+    challenge::{
+        ActionType, CaptchaChallenge, Challenge, ChallengeType, ChefChallenge, RequiredChallenge,
+    },
+    client::Client,
+    request::ApiRequest,
+    solver::{ChallengeSolver, ChefSolverFuture, SolverFuture},
+};
+
+mod common;
+
+use common::Response;
+
+struct CountingSolver(Arc<AtomicU32>);
+
+impl ChallengeSolver for CountingSolver {
+    fn solve<'a>(&'a self, _client: &'a mut Client, challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(challenge.id, "challenge-id");
+            assert_eq!(challenge.kind, ChallengeType::TwoStepVerification);
+            assert_eq!(challenge.metadata.user_id().unwrap(), 1);
+
+            Err(Error::ApiError(ApiError::ChallengeFailed))
+        })
+    }
+}
+
+// always answers with the same challenge
+async fn challenging_server(kind: &'static str, metadata: &'static str) -> String {
+    common::serve(move |_| {
+        Response::json(
+            r#"{"errors":[{"code":0,"message":"Challenge is required to authorize the request"}]}"#,
+        )
+        .status(403)
+        .header("rblx-challenge-id", "challenge-id")
+        .header("rblx-challenge-type", kind)
+        .header("rblx-challenge-metadata", &BASE64_STANDARD.encode(metadata))
+    })
+    .await
+}
+
+#[tokio::test]
+async fn solver_is_called() {
+    let url = challenging_server(
+        "twostepverification",
+        r#"{"userId":"1","challengeId":"server-id","actionType":"Generic","rememberDevice":false}"#,
+    )
+    .await;
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let mut client = Client::from_cookie("".into());
+    let result = ApiRequest::post(&url, "").send(&mut client).await;
+    assert!(matches!(
+        result,
+        Err(Error::ApiError(ApiError::ChallengeRequired(_)))
+    ));
+
+    client.set_challenge_solver(CountingSolver(calls.clone()));
+
+    // the solver is put back after solving, so it works more than once
+    for i in 1..=2 {
+        let result = ApiRequest::post(&url, "").send(&mut client).await;
+        assert!(matches!(
+            result,
+            Err(Error::ApiError(ApiError::ChallengeFailed))
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), i);
+    }
+
+    // requests have to stay spawnable
+    tokio::spawn(async move { ApiRequest::post(&url, "").send(&mut client).await.is_err() })
+        .await
+        .unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+struct TokenSolver;
+
+impl ChallengeSolver for TokenSolver {
+    fn solve<'a>(&'a self, _client: &'a mut Client, _challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async { Ok("verification-token".to_string()) })
+    }
+}
+
+#[tokio::test]
+async fn solved_request_is_replayed() {
+    let metadata = BASE64_STANDARD.encode(
+        r#"{"userId":"1","challengeId":"server-id","actionType":"Generic","rememberDevice":false}"#,
+    );
+    let replays = Arc::new(Mutex::new(Vec::new()));
+    let continued = Arc::new(AtomicU32::new(0));
+
+    let url = {
+        let replays = replays.clone();
+        let continued = continued.clone();
+        common::serve(move |request| match request.path.as_str() {
+            "/continue" => {
+                assert!(request.body.contains("verification-token"));
+                assert!(
+                    request
+                        .body
+                        .contains(r#""challengeType":"TwoStepVerification""#)
+                );
+                continued.fetch_add(1, Ordering::SeqCst);
+                Response::json("{}")
+            }
+
+            "/resource" if request.header("rblx-challenge-id").is_some() => {
+                replays.lock().unwrap().push(
+                    ["rblx-challenge-id", "rblx-challenge-type", "rblx-challenge-metadata"]
+                        .map(|x| request.header(x).unwrap().to_string()),
+                );
+                Response::json(r#""ok""#)
+            }
+
+            _ => Response::json(
+                r#"{"errors":[{"code":0,"message":"Challenge is required to authorize the request"}]}"#,
+            )
+            .status(403)
+            .header("rblx-challenge-id", "challenge-id")
+            .header("rblx-challenge-type", "twostepverification")
+            .header("rblx-challenge-metadata", &metadata),
+        })
+        .await
+    };
+
+    let mut client = Client::from_cookie("".into());
+    client.set_service_url(challenge::v1::URL, &url);
+    client.set_challenge_solver(TokenSolver);
+
+    let result = ApiRequest::post(&url, "resource")
+        .send(&mut client)
+        .await
+        .unwrap()
+        .json::<String>()
+        .await
+        .unwrap();
+
+    assert_eq!(result, "ok");
+    assert_eq!(continued.load(Ordering::SeqCst), 1);
+
+    let replays = replays.lock().unwrap();
+    let [id, kind, metadata] = &replays[0];
+    assert_eq!(id, "challenge-id");
+    // generic challenges are answered with `ChallengeType`'s names, not the lowercase ones
+    assert_eq!(kind, "TwoStepVerification");
+
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&BASE64_STANDARD.decode(metadata).unwrap()).unwrap();
+    assert_eq!(metadata["verificationToken"], "verification-token");
+    assert_eq!(metadata["challengeId"], "server-id");
+}
+
+// only solves captcha and chef challenges
+struct ScriptSolver;
+
+impl ChallengeSolver for ScriptSolver {
+    fn solve<'a>(&'a self, _client: &'a mut Client, _challenge: &'a Challenge) -> SolverFuture<'a> {
+        unreachable!()
+    }
+
+    fn solve_captcha<'a>(
+        &'a self,
+        _client: &'a mut Client,
+        challenge: &'a CaptchaChallenge,
+    ) -> SolverFuture<'a> {
+        assert_eq!(challenge.metadata.unified_captcha_id, "captcha-id");
+        Box::pin(async { Ok("captcha-token".to_string()) })
+    }
+
+    fn solve_chef<'a>(
+        &'a self,
+        _client: &'a mut Client,
+        challenge: &'a ChefChallenge,
+    ) -> ChefSolverFuture<'a> {
+        assert_eq!(challenge.metadata.server_challenge_id, "server-id");
+        Box::pin(async { Ok(serde_json::json!({ "result": "chef-result" })) })
+    }
+}
+
+#[tokio::test]
+async fn captcha_and_chef_are_solved() {
+    let challenges = [
+        (
+            "captcha",
+            r#"{"unifiedCaptchaId":"captcha-id","dataExchangeBlob":"blob","actionType":"Login"}"#,
+            "captcha-token",
+        ),
+        (
+            "chef",
+            r#"{"userId":"1","challengeId":"server-id","expectedSymbols":[],"scriptIdentifiers":[]}"#,
+            "chef-result",
+        ),
+    ];
+
+    for (kind, metadata, solution) in challenges {
+        let metadata = BASE64_STANDARD.encode(metadata);
+        let url = common::serve(move |request| match request.path.as_str() {
+            "/continue" => {
+                assert!(request.body.contains(solution));
+                assert!(
+                    request
+                        .body
+                        .contains(&format!(r#""challengeType":"{kind}""#))
+                );
+                Response::json("{}")
+            }
+
+            "/resource" if request.header("rblx-challenge-id").is_some() => {
+                assert_eq!(request.header("rblx-challenge-type"), Some(kind));
+
+                let metadata = request.header("rblx-challenge-metadata").unwrap();
+                let metadata = BASE64_STANDARD.decode(metadata).unwrap();
+                assert!(String::from_utf8(metadata).unwrap().contains(solution));
+
+                Response::json(r#""ok""#)
+            }
+
+            _ => Response::json(
+                r#"{"errors":[{"code":0,"message":"Challenge is required to authorize the request"}]}"#,
+            )
+            .status(403)
+            .header("rblx-challenge-id", "challenge-id")
+            .header("rblx-challenge-type", kind)
+            .header("rblx-challenge-metadata", &metadata),
+        })
+        .await;
+
+        let mut client = Client::from_cookie("".into());
+        client.set_service_url(challenge::v1::URL, &url);
+        client.set_challenge_solver(ScriptSolver);
+
+        let result = ApiRequest::post(&url, "resource")
+            .send(&mut client)
+            .await
+            .unwrap()
+            .json::<String>()
+            .await
+            .unwrap();
+        assert_eq!(result, "ok");
+    }
+}
+
+// never finishes the first challenge, fails the ones after it
+struct StuckSolver(Arc<AtomicU32>);
+
+impl ChallengeSolver for StuckSolver {
+    fn solve<'a>(&'a self, _client: &'a mut Client, _challenge: &'a Challenge) -> SolverFuture<'a> {
+        Box::pin(async move {
+            if self.0.fetch_add(1, Ordering::SeqCst) == 0 {
+                std::future::pending::<()>().await;
+            }
+
+            Err(Error::ApiError(ApiError::ChallengeFailed))
+        })
+    }
+}
+
+#[tokio::test]
+async fn solver_survives_dropped_call() {
+    let url = challenging_server(
+        "twostepverification",
+        r#"{"userId":"1","challengeId":"server-id","actionType":"Generic","rememberDevice":false}"#,
+    )
+    .await;
+    let calls = Arc::new(AtomicU32::new(0));
+
+    let mut client = Client::from_cookie("".into());
+    client.set_challenge_solver(StuckSolver(calls.clone()));
+
+    let dropped = tokio::time::timeout(
+        std::time::Duration::from_millis(200),
+        ApiRequest::post(&url, "").send(&mut client),
+    )
+    .await;
+    assert!(dropped.is_err());
+
+    // still there, so it's asked again
+    let result = ApiRequest::post(&url, "").send(&mut client).await;
+    assert!(matches!(
+        result,
+        Err(Error::ApiError(ApiError::ChallengeFailed))
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn captcha_challenge() {
+    let url = challenging_server(
+        "captcha",
+        r#"{"unifiedCaptchaId":"captcha-id","dataExchangeBlob":"blob","actionType":"Login"}"#,
+    )
+    .await;
+
+    let mut client = Client::from_cookie("".into());
+    let result = ApiRequest::post(&url, "").send(&mut client).await;
+
+    let Err(Error::ApiError(ApiError::ChallengeRequired(RequiredChallenge::Captcha(challenge)))) =
+        result
+    else {
+        panic!("expected a captcha challenge");
+    };
+
+    assert_eq!(challenge.id, "challenge-id");
+    assert_eq!(challenge.metadata.unified_captcha_id, "captcha-id");
+    assert_eq!(challenge.metadata.blob, "blob");
+    assert_eq!(challenge.metadata.action_type, ActionType::Login);
+}
+
+#[tokio::test]
+async fn chef_challenge() {
+    let url = challenging_server(
+        "chef",
+        r#"{"userId":"1","challengeId":"server-id","expectedSymbols":["a","b"],"scriptIdentifiers":["script"]}"#,
+    )
+    .await;
+
+    let mut client = Client::from_cookie("".into());
+    let result = ApiRequest::post(&url, "").send(&mut client).await;
+
+    let Err(Error::ApiError(ApiError::ChallengeRequired(RequiredChallenge::Chef(challenge)))) =
+        result
+    else {
+        panic!("expected a chef challenge");
+    };
+
+    assert_eq!(challenge.id, "challenge-id");
+    assert_eq!(challenge.metadata.user_id().unwrap(), 1);
+    assert_eq!(challenge.metadata.server_challenge_id, "server-id");
+    assert_eq!(challenge.metadata.expected_symbols, vec!["a", "b"]);
+    assert_eq!(challenge.metadata.script_identifiers, vec!["script"]);
+}
+
+#[tokio::test]
+async fn malformed_challenge() {
+    let mut client = Client::from_cookie("".into());
+
+    for kind in ["captcha", "chef"] {
+        let url = challenging_server(kind, "not json").await;
+        let result = ApiRequest::post(&url, "").send(&mut client).await;
+        assert!(matches!(
+            result,
+            Err(Error::ApiError(ApiError::Unknown(403, _)))
+        ));
+    }
+
+    // no challenge headers at all
+    let url = common::serve(|_| {
+        Response::json(
+            r#"{"errors":[{"code":0,"message":"Challenge is required to authorize the request"}]}"#,
+        )
+        .status(403)
+    })
+    .await;
+    let result = ApiRequest::post(&url, "").send(&mut client).await;
+    assert!(matches!(
+        result,
+        Err(Error::ApiError(ApiError::Unknown(403, _)))
+    ));
+}
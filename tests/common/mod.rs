@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use roblox_api::{Error, client::Client, request::RequestPlan};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Response {
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+async fn read_request(socket: &mut TcpStream) -> Request {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = buffer.windows(4).position(|x| x == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap().split(' ');
+    let method = request_line.next().unwrap().to_string();
+    let path = request_line.next().unwrap().to_string();
+
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|x| x.split_once(": "))
+        .map(|(key, value)| (key.to_lowercase(), value.to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(key, _)| key == "content-length")
+        .map(|(_, value)| value.parse::<usize>().unwrap())
+        .unwrap_or(0);
+
+    while buffer.len() < header_end + content_length {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&buffer[header_end..]).to_string(),
+    }
+}
+
+/// A local stand-in for roblox's servers, answers every request with `handler`
+pub async fn serve<F>(handler: F) -> String
+where
+    F: Fn(Request) -> Response + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let request = read_request(&mut socket).await;
+                let response = handler(request);
+
+                let mut head = format!(
+                    "HTTP/1.1 {} OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+
+                for (name, value) in &response.headers {
+                    head.push_str(&format!("{name}: {value}\r\n"));
+                }
+
+                socket
+                    .write_all(format!("{head}\r\n{}", response.body).as_bytes())
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    url
+}
+
+/// A client that records mutating requests instead of sending them
+pub fn dry_run_client() -> Client {
+    let mut client = Client::from_cookie("".into());
+    client.set_dry_run(true);
+    client
+}
+
+/// The request a dry-run client recorded instead of sending
+pub fn recorded<T>(result: Result<T, Error>) -> RequestPlan {
+    match result {
+        Err(Error::DryRun(plan)) => plan,
+        Err(error) => panic!("expected the request to be recorded, got {error:?}"),
+        Ok(_) => panic!("expected the request to be recorded"),
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use p256::{
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+    pkcs8::DecodePublicKey,
+};
+use roblox_api::{client::Client, hba::HbaKey, request::ApiRequest};
+use sha2::{Digest, Sha256};
+
+mod common;
+
+fn verify(key: &HbaKey, message: &str, signature: &str) {
+    let public_key = BASE64_STANDARD.decode(key.public_key()).unwrap();
+    let public_key = VerifyingKey::from_public_key_der(&public_key).unwrap();
+
+    let signature = BASE64_STANDARD.decode(signature).unwrap();
+    let signature = Signature::from_slice(&signature).unwrap();
+
+    public_key.verify(message.as_bytes(), &signature).unwrap();
+}
+
+#[test]
+fn export_import() {
+    let key = HbaKey::generate();
+    let imported = HbaKey::import(&key.export()).unwrap();
+
+    assert_eq!(key, imported);
+    assert_eq!(key.public_key(), imported.public_key());
+    assert!(HbaKey::import("not a key").is_none());
+}
+
+#[test]
+fn bound_auth_token() {
+    let key = HbaKey::generate();
+    let url = "https://users.roblox.com/v1/users/authenticated";
+    let token = key.bound_auth_token_at(1700000000, url, "POST", b"{}");
+
+    let parts: Vec<&str> = token.split('|').collect();
+    assert_eq!(parts.len(), 5);
+    assert_eq!(parts[0], "v1");
+    assert_eq!(parts[1], BASE64_STANDARD.encode(Sha256::digest(b"{}")));
+    assert_eq!(parts[2], "1700000000");
+
+    verify(
+        &key,
+        &format!("{}|1700000000|{url}|POST", parts[1]),
+        parts[3],
+    );
+    verify(&key, &format!("|1700000000|{url}|POST"), parts[4]);
+}
+
+#[tokio::test]
+async fn requests_are_signed() {
+    let tokens = Arc::new(Mutex::new(Vec::new()));
+    let url = {
+        let tokens = tokens.clone();
+        common::serve(move |request| {
+            tokens
+                .lock()
+                .unwrap()
+                .push(request.header("x-bound-auth-token").map(str::to_string));
+            common::Response::json("{}")
+        })
+        .await
+    };
+
+    let mut client = Client::from_cookie("".into());
+    ApiRequest::post(&url, "").send(&mut client).await.unwrap();
+
+    let key = HbaKey::generate();
+    client.set_hba_key(key.clone());
+    ApiRequest::post(&url, "").send(&mut client).await.unwrap();
+
+    let tokens = tokens.lock().unwrap();
+    assert_eq!(tokens[0], None);
+
+    let token = tokens[1].as_ref().unwrap();
+    let parts: Vec<&str> = token.split('|').collect();
+    verify(&key, &format!("|{}|{url}/|POST", parts[2]), parts[4]);
+}
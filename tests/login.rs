@@ -0,0 +1,200 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use roblox_api::{
+    api::{auth, challenge, hba_service, two_step_verification},
+    challenge::RequiredChallenge,
+    client::{Client, Cookie},
+    login::{LoginFlow, LoginState},
+};
+
+mod common;
+
+use common::{Request, Response};
+
+const USER: &str = r#"{"id":1,"name":"Roblox","displayName":"Roblox"}"#;
+
+// every service the flow talks to, on one stand-in server
+async fn client<F>(handler: F) -> Client
+where
+    F: Fn(Request) -> Response + Send + Sync + 'static,
+{
+    let url = common::serve(handler).await;
+
+    let mut client = Client::from_cookie("".into());
+    client.set_service_url(auth::v1::URL, &format!("{url}/auth/v1"));
+    client.set_service_url(auth::v3::URL, &format!("{url}/auth/v3"));
+    client.set_service_url(hba_service::v1::URL, &format!("{url}/hba"));
+    client.set_service_url(challenge::v1::URL, &format!("{url}/challenge"));
+    client.set_service_url(two_step_verification::v1::URL, &format!("{url}/2sv"));
+    client
+}
+
+fn logged_in() -> Response {
+    Response::json(format!(r#"{{"user":{USER}}}"#))
+        .header("set-cookie", ".ROBLOSECURITY=session; path=/; HttpOnly")
+}
+
+fn csrf_failed() -> Response {
+    Response::json(r#"{"errors":[{"code":0,"message":"Token Validation Failed"}]}"#)
+        .status(403)
+        .header("x-csrf-token", "csrf")
+}
+
+#[tokio::test]
+async fn login() {
+    let mut client = client(|request| match request.path.as_str() {
+        "/hba/getservernonce" => Response::json(r#""nonce""#),
+        "/auth/v1/login" if request.header("x-csrf-token").is_none() => csrf_failed(),
+        "/auth/v1/login" => {
+            assert!(request.body.contains(r#""cvalue":"Roblox""#));
+            assert!(request.body.contains(r#""serverNonce":"nonce""#));
+            logged_in()
+        }
+        path => panic!("unexpected path {path}"),
+    })
+    .await;
+
+    let mut flow = LoginFlow::username("Roblox", "password");
+    let state = flow.start(&mut client).await.unwrap();
+
+    assert_eq!(
+        state,
+        LoginState::LoggedIn {
+            user_id: 1,
+            cookie: Cookie::from("session"),
+        }
+    );
+    assert_eq!(client.cookie(), Some(Cookie::from("session")));
+}
+
+#[tokio::test]
+async fn two_step_verification() {
+    let mut client = client(|request| match request.path.as_str() {
+        "/hba/getservernonce" => Response::json(r#""nonce""#),
+        "/auth/v1/login" => Response::json(format!(
+            r#"{{"user":{USER},"twoStepVerificationData":{{"mediaType":"Authenticator","ticket":"ticket"}}}}"#
+        )),
+        path if path.starts_with("/2sv/users/1/configuration") => {
+            assert!(path.contains("challengeId=ticket"));
+            Response::json(
+                r#"{"primaryMediaType":"Authenticator","methods":[{"mediaType":"Authenticator","enabled":true},{"mediaType":"Email","enabled":false}]}"#,
+            )
+        }
+        "/2sv/users/1/challenges/authenticator/verify" => {
+            assert!(request.body.contains(r#""code":"123456""#));
+            assert!(request.body.contains(r#""challengeId":"ticket""#));
+            Response::json(r#"{"verificationToken":"verification"}"#)
+        }
+        "/auth/v3/users/1/two-step-verification/login" => {
+            assert!(request.body.contains(r#""verificationToken":"verification""#));
+            assert!(request.body.contains(r#""challengeId":"ticket""#));
+            logged_in()
+        }
+        path => panic!("unexpected path {path}"),
+    })
+    .await;
+
+    let mut flow = LoginFlow::username("Roblox", "password");
+
+    // nothing to submit a code for yet
+    assert!(flow.submit_code(&mut client, "123456").await.is_err());
+
+    let state = flow.start(&mut client).await.unwrap();
+    let LoginState::MethodRequired { methods, primary } = state else {
+        panic!("expected a method to be asked for");
+    };
+    assert_eq!(
+        methods,
+        vec![two_step_verification::v1::MediaType::Authenticator]
+    );
+    assert_eq!(
+        primary,
+        Some(two_step_verification::v1::MediaType::Authenticator)
+    );
+
+    let state = flow.select_method(&mut client, methods[0]).await.unwrap();
+    assert_eq!(
+        state,
+        LoginState::CodeRequired(two_step_verification::v1::MediaType::Authenticator)
+    );
+
+    let state = flow.submit_code(&mut client, "123456").await.unwrap();
+    assert_eq!(
+        state,
+        LoginState::LoggedIn {
+            user_id: 1,
+            cookie: Cookie::from("session"),
+        }
+    );
+    assert_eq!(client.cookie(), Some(Cookie::from("session")));
+}
+
+#[tokio::test]
+async fn challenge_is_resubmitted() {
+    let attempts = Arc::new(AtomicU32::new(0));
+    let metadata = BASE64_STANDARD
+        .encode(r#"{"unifiedCaptchaId":"captcha","dataExchangeBlob":"blob","actionType":"Login"}"#);
+
+    let mut client = {
+        let attempts = attempts.clone();
+        client(move |request| match request.path.as_str() {
+            "/hba/getservernonce" => Response::json(r#""nonce""#),
+            "/challenge/continue" => Response::json("{}"),
+            "/auth/v1/login" => match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => csrf_failed(),
+                1 => Response::json(
+                    r#"{"errors":[{"code":0,"message":"Challenge is required to authorize the request"}]}"#,
+                )
+                .status(403)
+                .header("rblx-challenge-id", "challenge-id")
+                .header("rblx-challenge-type", "captcha")
+                .header("rblx-challenge-metadata", &metadata),
+                // the token ran out, so the resubmitted login gets sent twice
+                2 => csrf_failed(),
+                _ => {
+                    assert_eq!(request.header("rblx-challenge-id"), Some("challenge-id"));
+                    assert_eq!(request.header("rblx-challenge-type"), Some("captcha"));
+
+                    let metadata = request.header("rblx-challenge-metadata").unwrap();
+                    let metadata = BASE64_STANDARD.decode(metadata).unwrap();
+                    assert!(String::from_utf8(metadata).unwrap().contains("captcha-token"));
+
+                    logged_in()
+                }
+            },
+            path => panic!("unexpected path {path}"),
+        })
+        .await
+    };
+
+    let mut flow = LoginFlow::username("Roblox", "password");
+    let state = flow.start(&mut client).await.unwrap();
+    let LoginState::ChallengeRequired(RequiredChallenge::Captcha(challenge)) = state else {
+        panic!("expected a captcha challenge");
+    };
+
+    client
+        .queue_captcha_challenge(&challenge, "captcha-token")
+        .await
+        .unwrap();
+
+    let state = flow.start(&mut client).await.unwrap();
+    assert!(matches!(state, LoginState::LoggedIn { user_id: 1, .. }));
+    assert_eq!(attempts.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let flow = LoginFlow::username("Roblox", "hunter2");
+    assert!(!format!("{flow:?}").contains("hunter2"));
+
+    let state = LoginState::LoggedIn {
+        user_id: 1,
+        cookie: Cookie::from("session-secret"),
+    };
+    assert!(!format!("{state:?}").contains("session-secret"));
+}
@@ -0,0 +1,209 @@
+use roblox_api::{
+    Error,
+    api::oauth::v1::{self as oauth, OAuthConfig, OAuthEndpoints, Pkce},
+    client::Client,
+    request::ApiRequest,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+// answers like roblox's authorization server would, just enough for the flow
+async fn handle(mut socket: TcpStream) {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = buffer.windows(4).position(|x| x == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .find_map(|x| {
+            x.to_lowercase()
+                .strip_prefix("content-length: ")
+                .map(|x| x.to_string())
+        })
+        .map(|x| x.parse::<usize>().unwrap())
+        .unwrap_or(0);
+
+    while buffer.len() < header_end + content_length {
+        let read = socket.read(&mut chunk).await.unwrap();
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    let body = String::from_utf8_lossy(&buffer[header_end..]).to_string();
+    let authorization = head
+        .lines()
+        .find_map(|x| x.strip_prefix("authorization: "))
+        .unwrap_or_default();
+
+    let request_line = head.lines().next().unwrap();
+    let response = match request_line.split(' ').nth(1).unwrap() {
+        "/token" if body.contains("grant_type=authorization_code") => {
+            assert!(body.contains("code=the-code"));
+            assert!(body.contains("code_verifier=verifier"));
+            assert!(body.contains("client_secret=secret"));
+
+            // already expired, so the next call has to refresh it
+            r#"{"access_token":"access-1","refresh_token":"refresh-1","token_type":"Bearer","expires_in":0,"scope":"openid"}"#.to_string()
+        }
+
+        "/token" => {
+            assert!(body.contains("grant_type=refresh_token"));
+            assert!(body.contains("refresh_token=refresh-1"));
+
+            r#"{"access_token":"access-2","refresh_token":"refresh-2","token_type":"Bearer","expires_in":900,"scope":"openid"}"#.to_string()
+        }
+
+        "/token/introspect" => r#"{"active":true,"sub":"1","client_id":"app"}"#.to_string(),
+        "/token/revoke" => String::new(),
+        "/userinfo" => format!(r#"{{"sub":"1","preferred_username":"{authorization}"}}"#),
+        "/resource" => format!(r#""{authorization}""#),
+
+        path => panic!("unexpected path {path}"),
+    };
+
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        response.len(),
+        response
+    );
+
+    socket.write_all(response.as_bytes()).await.unwrap();
+}
+
+async fn authorization_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(handle(socket));
+        }
+    });
+
+    url
+}
+
+#[test]
+fn authorization_url() {
+    let config =
+        OAuthConfig::new("app", None, "http://localhost/callback").scopes(&["openid", "profile"]);
+
+    // the example from rfc 7636
+    let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    assert_eq!(
+        pkce.challenge,
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+
+    let url = oauth::authorization_url(&config, &pkce, "state");
+    assert_eq!(
+        url,
+        "https://apis.roblox.com/oauth/v1/authorize?client_id=app&redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&scope=openid+profile&response_type=code&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256&state=state"
+    );
+
+    assert_ne!(Pkce::new().verifier, Pkce::new().verifier);
+}
+
+#[tokio::test]
+async fn flow() {
+    let url = authorization_server().await;
+    let config = OAuthConfig::new("app", Some("secret"), "http://localhost/callback")
+        .endpoints(OAuthEndpoints::from_base(&url));
+
+    let mut client = Client::default();
+    let tokens = oauth::exchange_code(
+        &mut client,
+        &config,
+        "the-code",
+        &Pkce::from_verifier("verifier"),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(tokens.access_token, "access-1");
+
+    let introspection = oauth::introspect(&mut client, &config, &tokens.access_token)
+        .await
+        .unwrap();
+    assert!(introspection.is_active);
+
+    let info = oauth::userinfo(&mut client, &config, &tokens.access_token)
+        .await
+        .unwrap();
+    assert_eq!(info.username.unwrap(), "Bearer access-1");
+
+    // the token is already expired, so it gets refreshed before the request
+    let mut client = Client::from_oauth_token(config.clone(), tokens);
+    let authorization = ApiRequest::get(&url, "resource")
+        .open_cloud()
+        .send(&mut client)
+        .await
+        .unwrap()
+        .json::<String>()
+        .await
+        .unwrap();
+
+    assert_eq!(authorization, "Bearer access-2");
+    assert_eq!(client.oauth_tokens().unwrap().refresh_token, "refresh-2");
+
+    oauth::revoke(&mut client, &config, "refresh-2")
+        .await
+        .unwrap();
+
+    let result = ApiRequest::get(&url, "resource").send(&mut client).await;
+    assert!(matches!(result, Err(Error::CookieRequired)));
+}
+
+#[tokio::test]
+async fn dry_run_doesnt_refresh() {
+    // nothing listens here, a token request would fail the call
+    let config = OAuthConfig::new("app", None, "http://localhost/callback")
+        .endpoints(OAuthEndpoints::from_base("http://127.0.0.1:1"));
+    let tokens = serde_json::from_str(
+        r#"{"access_token":"access-1","refresh_token":"refresh-1","token_type":"Bearer","expires_in":0,"scope":"openid"}"#,
+    )
+    .unwrap();
+
+    let mut client = Client::from_oauth_token(config.clone(), tokens);
+    client.set_dry_run(true);
+
+    let result = ApiRequest::post("http://127.0.0.1:1", "resource")
+        .open_cloud()
+        .send(&mut client)
+        .await;
+    assert!(matches!(result, Err(Error::DryRun(_))));
+    assert_eq!(client.oauth_tokens().unwrap().refresh_token, "refresh-1");
+
+    // a token that can't be a header is an error, not a panic
+    let result = oauth::userinfo(&mut client, &config, "access\n").await;
+    assert!(result.is_err());
+}
+
+#[test]
+fn debug_redacts_secrets() {
+    let config = OAuthConfig::new("app", Some("client-secret"), "http://localhost/callback");
+    let tokens: oauth::TokenResponse = serde_json::from_str(
+        r#"{"access_token":"access-secret","refresh_token":"refresh-secret","token_type":"Bearer","expires_in":900,"id_token":"id-secret","scope":"openid"}"#,
+    )
+    .unwrap();
+
+    let client = Client::from_oauth_token(config, tokens);
+    let debug = format!("{client:?}");
+    assert!(debug.contains("<redacted>"));
+    for secret in [
+        "client-secret",
+        "access-secret",
+        "refresh-secret",
+        "id-secret",
+    ] {
+        assert!(!debug.contains(secret), "{secret} is in {debug}");
+    }
+}
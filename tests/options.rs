@@ -0,0 +1,151 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use roblox_api::{
+    Error,
+    client::Client,
+    options::{CallOptions, CancellationToken},
+    request::ApiRequest,
+};
+use tokio::net::TcpListener;
+
+mod common;
+
+// a server that accepts connections but never answers
+async fn silent_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        let mut sockets = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            sockets.push(socket);
+        }
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn timeout() {
+    let url = silent_server().await;
+    let mut client = Client::from_cookie("".into());
+
+    let result = ApiRequest::post(&url, "")
+        .options(CallOptions::new().timeout(Duration::from_millis(100)))
+        .send(&mut client)
+        .await;
+
+    match result {
+        Err(Error::ReqwestError(error)) => assert!(error.is_timeout()),
+        _ => panic!("expected a timeout"),
+    }
+}
+
+#[tokio::test]
+async fn client_options_timeout() {
+    let url = silent_server().await;
+    let mut client = Client::from_cookie("".into());
+
+    let result = ApiRequest::post(&url, "")
+        .send(client.with_options(CallOptions::new().timeout(Duration::from_millis(100))))
+        .await;
+
+    assert!(matches!(result, Err(Error::ReqwestError(_))));
+}
+
+#[tokio::test]
+async fn cancellation() {
+    let url = silent_server().await;
+    let mut client = Client::from_cookie("".into());
+
+    let token = CancellationToken::new();
+    {
+        let token = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        });
+    }
+
+    let result = ApiRequest::post(&url, "")
+        .options(CallOptions::new().cancellation(token))
+        .send(&mut client)
+        .await;
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+}
+
+// the keys the server got in the json bodies
+async fn record_keys() -> (String, Arc<Mutex<Vec<String>>>) {
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let url = {
+        let keys = keys.clone();
+        common::serve(move |request| {
+            let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+            keys.lock()
+                .unwrap()
+                .push(body["idempotencyKey"].as_str().unwrap().to_string());
+            common::Response::json("{}")
+        })
+        .await
+    };
+
+    (url, keys)
+}
+
+#[tokio::test]
+async fn idempotency_key() {
+    let (url, keys) = record_keys().await;
+    let mut client = Client::from_cookie("".into());
+    let request = ApiRequest::post(&url, "")
+        .json(&serde_json::json!({ "price": 5 }))
+        .idempotency_key("idempotencyKey");
+
+    // retrying with the same key, once per call and once on the client
+    request
+        .clone()
+        .options(CallOptions::new().idempotency_key("purchase-1"))
+        .send(&mut client)
+        .await
+        .unwrap();
+
+    request
+        .clone()
+        .send(client.with_options(CallOptions::new().idempotency_key("purchase-1")))
+        .await
+        .unwrap();
+
+    // without one, each call gets its own
+    request.clone().send(&mut client).await.unwrap();
+    request.send(&mut client).await.unwrap();
+
+    let keys = keys.lock().unwrap();
+    assert_eq!(keys[0], "purchase-1");
+    assert_eq!(keys[1], "purchase-1");
+    assert_ne!(keys[2], keys[3]);
+}
+
+#[tokio::test]
+async fn client_options_are_used_once() {
+    let (url, keys) = record_keys().await;
+    let mut client = Client::from_api_key("key".into());
+
+    // refused before being sent, the options still go with it
+    let result = ApiRequest::post(&url, "")
+        .send(client.with_options(CallOptions::new().idempotency_key("refused")))
+        .await;
+    assert!(matches!(result, Err(Error::CookieRequired)));
+
+    ApiRequest::post(&url, "")
+        .json(&serde_json::json!({}))
+        .idempotency_key("idempotencyKey")
+        .open_cloud()
+        .send(&mut client)
+        .await
+        .unwrap();
+
+    assert_ne!(keys.lock().unwrap()[0], "refused");
+}
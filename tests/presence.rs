@@ -0,0 +1,81 @@
+use roblox_api::api::presence::v1::{PresenceEvent, PresenceType, PresenceWatcher, UserPresence};
+
+fn presence(
+    id: u64,
+    kind: PresenceType,
+    place_id: Option<u64>,
+    job_id: Option<&str>,
+) -> UserPresence {
+    UserPresence {
+        id,
+        kind: kind as u8,
+        status: String::new(),
+        place_id,
+        root_place_id: place_id,
+        universe_id: place_id.map(|x| x + 1),
+        job_id: job_id.map(str::to_string),
+    }
+}
+
+#[test]
+fn watcher_events() {
+    let mut watcher = PresenceWatcher::new(vec![1, 2, 3]);
+
+    // the first update is only a baseline
+    let events = watcher.update(vec![
+        presence(1, PresenceType::Offline, None, None),
+        presence(2, PresenceType::Online, None, None),
+        presence(3, PresenceType::InGame, Some(10), Some("a")),
+    ]);
+    assert!(events.is_empty());
+
+    let events = watcher.update(vec![
+        presence(1, PresenceType::InGame, Some(20), Some("b")),
+        presence(2, PresenceType::Offline, None, None),
+        presence(3, PresenceType::InGame, Some(30), Some("c")),
+    ]);
+    assert_eq!(
+        events,
+        vec![
+            PresenceEvent::WentOnline { user_id: 1 },
+            PresenceEvent::JoinedGame {
+                user_id: 1,
+                place_id: Some(20),
+                universe_id: Some(21),
+                job_id: Some("b".into()),
+            },
+            PresenceEvent::WentOffline { user_id: 2 },
+            PresenceEvent::LeftGame {
+                user_id: 3,
+                place_id: Some(10),
+                universe_id: Some(11),
+            },
+            PresenceEvent::JoinedGame {
+                user_id: 3,
+                place_id: Some(30),
+                universe_id: Some(31),
+                job_id: Some("c".into()),
+            },
+        ]
+    );
+
+    let events = watcher.update(vec![
+        presence(1, PresenceType::InGame, Some(20), Some("b")),
+        presence(3, PresenceType::Offline, None, None),
+    ]);
+    assert_eq!(
+        events,
+        vec![
+            PresenceEvent::LeftGame {
+                user_id: 3,
+                place_id: Some(30),
+                universe_id: Some(31),
+            },
+            PresenceEvent::WentOffline { user_id: 3 },
+        ]
+    );
+    assert_eq!(
+        watcher.presence(3).unwrap().presence_type(),
+        Some(PresenceType::Offline)
+    );
+}
@@ -0,0 +1,117 @@
+use reqwest::{
+    Method,
+    header::{self, HeaderValue},
+};
+use roblox_api::{
+    Error, Paging, SortOrder,
+    api::{cloud, groups, users},
+    client::Client,
+    request::{ApiRequest, RequestBody, ResponseKind},
+};
+use serde::Serialize;
+
+#[test]
+fn request_builder() {
+    #[derive(Serialize)]
+    struct Request {
+        name: &'static str,
+    }
+
+    let request = ApiRequest::post(groups::v1::URL, "groups/1/roles")
+        .query("isPrimary", true)
+        .paging(
+            Paging::new(Some("abc"), None, Some(SortOrder::Descending)),
+            25,
+        )
+        .json(&Request { name: "Member" })
+        .header(header::ACCEPT, HeaderValue::from_static("*/*"))
+        .expect(ResponseKind::Empty);
+
+    assert_eq!(request.method(), &Method::POST);
+    assert_eq!(request.url(), "https://groups.roblox.com/v1/groups/1/roles");
+    assert_eq!(
+        request.query_pairs(),
+        &[
+            ("isPrimary".to_string(), "true".to_string()),
+            ("limit".to_string(), "25".to_string()),
+            ("sortOrder".to_string(), "Descending".to_string()),
+            ("cursor".to_string(), "abc".to_string()),
+        ]
+    );
+    assert_eq!(
+        request.body(),
+        &RequestBody::Json(serde_json::json!({ "name": "Member" }))
+    );
+    assert_eq!(request.headers().get(header::ACCEPT).unwrap(), "*/*");
+    assert_eq!(request.response_kind(), ResponseKind::Empty);
+}
+
+#[tokio::test]
+async fn request_builder_edge_cases() {
+    let request = ApiRequest::get(groups::v1::URL, "groups/1/users").paging(Paging::default(), 10);
+    assert_eq!(
+        request.query_pairs(),
+        &[
+            ("limit".to_string(), "10".to_string()),
+            ("sortOrder".to_string(), "Ascending".to_string()),
+        ]
+    );
+
+    // json objects can't have non-string keys
+    let body = std::collections::HashMap::from([((1, 2), 3)]);
+    let mut client = Client::from_cookie("".into());
+    let result = ApiRequest::post(groups::v1::URL, "groups/1")
+        .json(&body)
+        .send(&mut client)
+        .await;
+    assert!(matches!(result, Err(Error::BadJson)));
+}
+
+#[tokio::test]
+async fn dry_run() {
+    let mut client = Client::from_cookie("".into());
+    client.set_dry_run(true);
+
+    let result = groups::v1::remove(&mut client, 1, 2).await;
+    let Err(Error::DryRun(plan)) = result else {
+        panic!("expected the request to be recorded");
+    };
+
+    assert_eq!(plan.method, "DELETE");
+    assert_eq!(plan.url, "https://groups.roblox.com/v1/groups/1/users/2");
+    assert_eq!(plan.body, Some(serde_json::json!({})));
+    assert_eq!(client.take_plans(), vec![plan]);
+    assert!(client.plans().is_empty());
+}
+
+#[tokio::test]
+async fn api_key_refuses_cookie_only_endpoints() {
+    let mut client = Client::from_api_key("key".into());
+
+    let result = users::v1::user_details(&mut client, 1).await;
+    assert!(matches!(result, Err(Error::CookieRequired)));
+
+    client.set_dry_run(true);
+    let result = cloud::v2::restart_servers(&mut client, 1).await;
+    let Err(Error::DryRun(plan)) = result else {
+        panic!("expected the request to be recorded");
+    };
+
+    assert_eq!(
+        plan.url,
+        "https://apis.roblox.com/cloud/v2/universes/1:restartServers"
+    );
+}
+
+#[test]
+fn cookie() {
+    let mut client = Client::from_cookie("abc".into());
+    assert_eq!(client.cookie(), Some("abc".into()));
+
+    client.set_cookie("def".into());
+    assert_eq!(client.cookie().unwrap().value(), "def");
+
+    client.clear_cookie();
+    assert_eq!(client.cookie(), None);
+    assert!(Client::from_cookie("".into()).cookie().is_none());
+}
@@ -0,0 +1,39 @@
+use roblox_api::totp::Totp;
+
+// the sha1 test vectors from rfc 6238
+#[test]
+fn rfc_6238() {
+    let totp = Totp::new(b"12345678901234567890".to_vec())
+        .digits(8)
+        .unwrap();
+
+    assert_eq!(totp.code_at(59), "94287082");
+    assert_eq!(totp.code_at(1111111109), "07081804");
+    assert_eq!(totp.code_at(1111111111), "14050471");
+    assert_eq!(totp.code_at(1234567890), "89005924");
+    assert_eq!(totp.code_at(2000000000), "69279037");
+    assert_eq!(totp.code_at(20000000000), "65353130");
+}
+
+#[test]
+fn base32_secret() {
+    // "12345678901234567890" in base32
+    let totp = Totp::from_base32("gezd gnbv gy3t qojq gezd gnbv gy3t qojq").unwrap();
+    assert_eq!(totp, Totp::new(b"12345678901234567890".to_vec()));
+    assert_eq!(totp.code_at(59), "287082");
+
+    assert!(Totp::from_base32("not base32!").is_none());
+}
+
+#[test]
+fn invalid_settings() {
+    let totp = Totp::new(b"12345678901234567890".to_vec());
+
+    assert!(totp.clone().digits(0).is_none());
+    assert!(totp.clone().digits(20).is_none());
+    assert!(totp.clone().period(0).is_none());
+
+    let totp = totp.digits(10).unwrap().period(60).unwrap();
+    assert_eq!(totp.code_at(59).len(), 10);
+    assert!(format!("{totp:?}").contains(r#"secret: "<redacted>""#));
+}